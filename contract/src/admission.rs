@@ -0,0 +1,247 @@
+use crate::error::LumentixError;
+use crate::events::BadgeMinted;
+use crate::storage;
+use crate::types::{
+    AdmissionState, AttendanceBadge, CheckInResult, CheckInWindow, Event, SignedScan, Ticket,
+    NO_SESSION,
+};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, Env};
+
+/// Domain separator prefixed to every signed entry pass message
+const PASS_DOMAIN: &[u8] = b"lumentix-pass";
+
+/// Whether `caller` may check tickets in and out at an event's gates:
+/// the event organizer or one of its authorized validators.
+pub fn is_gate_agent(env: &Env, event: &Event, caller: &Address) -> bool {
+    event.organizer == *caller || storage::is_validator(env, event.id, caller)
+}
+
+/// Find the session of an event running at `now`.
+/// Returns NO_SESSION for events without sessions.
+pub fn active_session_id(env: &Env, event_id: u64, now: u64) -> Result<u32, LumentixError> {
+    let sessions = storage::get_sessions(env, event_id);
+    if sessions.is_empty() {
+        return Ok(NO_SESSION);
+    }
+
+    sessions
+        .iter()
+        .find(|s| s.start_time <= now && now <= s.end_time)
+        .map(|s| s.id)
+        .ok_or(LumentixError::SessionNotActive)
+}
+
+/// Ensure `now` falls inside the event's check-in window.
+/// Tickets granted early entry may check in before the window opens, but not after it closes.
+fn check_window(env: &Env, ticket: &Ticket, event: &Event, now: u64) -> Result<(), LumentixError> {
+    let (opens_before_start, closes_after_end) = match event.checkin_window {
+        CheckInWindow::Anytime => return Ok(()),
+        CheckInWindow::Relative(before, after) => (before, after),
+    };
+
+    let opens = event.start_time.saturating_sub(opens_before_start);
+    let closes = event.end_time.saturating_add(closes_after_end);
+
+    if now > closes {
+        return Err(LumentixError::OutsideCheckInWindow);
+    }
+    if now < opens && !storage::has_early_entry(env, ticket.id) {
+        return Err(LumentixError::OutsideCheckInWindow);
+    }
+    Ok(())
+}
+
+/// Whether a ticket admits to a session. Tickets with no explicit sessions admit to all.
+pub fn grants_session(ticket: &Ticket, session_id: u32) -> bool {
+    ticket.sessions.is_empty() || ticket.sessions.contains(session_id)
+}
+
+/// The earliest session granted by a ticket that starts after `now`, if any.
+fn next_granted_session(env: &Env, ticket: &Ticket, now: u64) -> Option<u32> {
+    storage::get_sessions(env, ticket.event_id)
+        .iter()
+        .filter(|s| s.start_time > now && grants_session(ticket, s.id))
+        .min_by_key(|s| s.start_time)
+        .map(|s| s.id)
+}
+
+/// Whether a ticket has checked in to every session it grants.
+fn attended_all_sessions(env: &Env, ticket: &Ticket) -> bool {
+    storage::get_sessions(env, ticket.event_id)
+        .iter()
+        .filter(|s| grants_session(ticket, s.id))
+        .all(|s| storage::get_admission(env, ticket.id, s.id).is_some())
+}
+
+/// Whether a ticket has checked in to any session of its event, or to the
+/// event itself when it has no sessions.
+pub fn has_checked_in(env: &Env, ticket: &Ticket) -> bool {
+    ticket.used
+        || storage::get_admission(env, ticket.id, NO_SESSION).is_some()
+        || storage::get_sessions(env, ticket.event_id)
+            .iter()
+            .any(|s| storage::get_admission(env, ticket.id, s.id).is_some())
+}
+
+/// Check a ticket in to the session of its event running at `now`.
+///
+/// Check-ins outside the event's check-in window are rejected.
+/// Events without sessions admit once, as before. Events with sessions admit once
+/// per granted session, and the ticket only becomes `used` after its last session.
+/// Once the ticket is used, the holder receives the event's attendance badge if enabled.
+/// A ticket that scanned out may re-enter while the event allows re-entry.
+/// Updates `ticket` in place; the caller persists it. Returns the session id.
+pub fn admit(
+    env: &Env,
+    ticket: &mut Ticket,
+    event: &Event,
+    now: u64,
+) -> Result<u32, LumentixError> {
+    if ticket.refunded {
+        return Err(LumentixError::RefundNotAllowed);
+    }
+
+    check_window(env, ticket, event, now)?;
+
+    // Early entry admits to the next granted session before it starts
+    let session_id = match active_session_id(env, event.id, now) {
+        Err(LumentixError::SessionNotActive) if storage::has_early_entry(env, ticket.id) => {
+            next_granted_session(env, ticket, now).ok_or(LumentixError::SessionNotActive)?
+        }
+        result => result?,
+    };
+    if session_id != NO_SESSION && !grants_session(ticket, session_id) {
+        return Err(LumentixError::SessionNotGranted);
+    }
+
+    match storage::get_admission(env, ticket.id, session_id) {
+        Some(AdmissionState::Inside) => return Err(LumentixError::TicketAlreadyUsed),
+        Some(AdmissionState::Outside) if !event.reentry_allowed => {
+            return Err(LumentixError::TicketAlreadyUsed)
+        }
+        Some(AdmissionState::Outside) => {}
+        None if session_id == NO_SESSION && ticket.used => {
+            return Err(LumentixError::TicketAlreadyUsed)
+        }
+        None => {}
+    }
+
+    storage::set_admission(env, ticket.id, session_id, AdmissionState::Inside);
+
+    ticket.used = session_id == NO_SESSION || attended_all_sessions(env, ticket);
+    if ticket.used {
+        award_badge(env, ticket, event, now);
+    }
+
+    Ok(session_id)
+}
+
+/// Mint the event's attendance badge for the ticket holder, if the event opted
+/// in and the holder does not have one yet.
+fn award_badge(env: &Env, ticket: &Ticket, event: &Event, now: u64) {
+    if !event.badges_enabled || storage::has_badge(env, event.id, &ticket.owner) {
+        return;
+    }
+
+    let badge = AttendanceBadge {
+        holder: ticket.owner.clone(),
+        event_id: event.id,
+        ticket_id: ticket.id,
+        checked_in_at: now,
+    };
+    storage::add_badge(env, &badge);
+
+    // Emit BadgeMinted event
+    BadgeMinted::emit(env, ticket.owner.clone(), event.id, ticket.id, now);
+}
+
+/// Scan a checked-in ticket out of the current session so it can re-enter later.
+/// Returns the session id.
+pub fn scan_out(env: &Env, ticket: &Ticket, event: &Event) -> Result<u32, LumentixError> {
+    if !event.reentry_allowed {
+        return Err(LumentixError::ReentryNotAllowed);
+    }
+
+    let session_id = active_session_id(env, event.id, env.ledger().timestamp())?;
+    if storage::get_admission(env, ticket.id, session_id) != Some(AdmissionState::Inside) {
+        return Err(LumentixError::TicketNotCheckedIn);
+    }
+
+    storage::set_admission(env, ticket.id, session_id, AdmissionState::Outside);
+
+    Ok(session_id)
+}
+
+/// Report a rejected check-in as a per-ticket batch result.
+pub fn check_in_result(err: LumentixError) -> CheckInResult {
+    match err {
+        LumentixError::TicketNotFound | LumentixError::EventNotFound => CheckInResult::NotFound,
+        LumentixError::TicketEventMismatch => CheckInResult::WrongEvent,
+        LumentixError::TicketAlreadyUsed => CheckInResult::AlreadyUsed,
+        LumentixError::RefundNotAllowed => CheckInResult::Refunded,
+        LumentixError::Unauthorized => CheckInResult::Unauthorized,
+        LumentixError::StaleScan => CheckInResult::StaleScan,
+        LumentixError::PassKeyNotRegistered => CheckInResult::NoPassKey,
+        _ => CheckInResult::Rejected,
+    }
+}
+
+/// Message a holder signs for an offline entry pass:
+/// `"lumentix-pass" || network_id || contract || ticket_id || event_id || timestamp`,
+/// with the contract address as XDR and integers as big-endian u64. Binding the
+/// network and contract keeps a pass from replaying on another deployment.
+pub fn pass_message(
+    env: &Env,
+    contract: &Address,
+    ticket_id: u64,
+    event_id: u64,
+    timestamp: u64,
+) -> Bytes {
+    let mut message = Bytes::from_slice(env, PASS_DOMAIN);
+    message.append(&env.ledger().network_id().into());
+    message.append(&contract.clone().to_xdr(env));
+    message.extend_from_array(&ticket_id.to_be_bytes());
+    message.extend_from_array(&event_id.to_be_bytes());
+    message.extend_from_array(&timestamp.to_be_bytes());
+    message
+}
+
+/// Verify a signed scan against the holder's registered pass key. Each scan must
+/// be newer than the last accepted scan of the ticket and must not be dated in the
+/// future; the caller records accepted scans with `storage::set_last_scan_time`.
+///
+/// Panics (via the host) if the signature is invalid.
+pub fn verify_signed_scan(
+    env: &Env,
+    ticket: &Ticket,
+    scan: &SignedScan,
+) -> Result<(), LumentixError> {
+    if scan.event_id != ticket.event_id {
+        return Err(LumentixError::TicketEventMismatch);
+    }
+
+    let public_key =
+        storage::get_pass_key(env, ticket.id).ok_or(LumentixError::PassKeyNotRegistered)?;
+
+    if scan.timestamp > env.ledger().timestamp() {
+        return Err(LumentixError::StaleScan);
+    }
+    if let Some(last) = storage::get_last_scan_time(env, ticket.id) {
+        if scan.timestamp <= last {
+            return Err(LumentixError::StaleScan);
+        }
+    }
+
+    let message = pass_message(
+        env,
+        &env.current_contract_address(),
+        scan.ticket_id,
+        scan.event_id,
+        scan.timestamp,
+    );
+    env.crypto()
+        .ed25519_verify(&public_key, &message, &scan.signature);
+
+    Ok(())
+}
@@ -1,7 +1,13 @@
-use crate::events::{CheckInEvent, TransferEvent};
-use crate::models::{DataKey, EscrowConfig, EventAuth, Ticket, ValidatorKey};
-use crate::types::TEMPORARY_LIFETIME;
-use soroban_sdk::{contract, contractimpl, log, Address, Env, Symbol, Vec};
+use crate::error::LumentixError;
+use crate::events::{
+    CheckInEvent, EscrowDeposited, EscrowDistributed, ProposalApproved, ProposalCreated,
+    ProposalRejected, TransferEvent,
+};
+use crate::models::{
+    AttendanceBadge, DataKey, EscrowConfig, EventAuth, ProposalStatus, ReleaseProposal, Ticket,
+};
+use crate::types::{MAX_PAGE_SIZE, PERSISTENT_LIFETIME, SECONDS_PER_LEDGER};
+use soroban_sdk::{contract, contractimpl, log, token, Address, Env, IntoVal, Symbol, Val, Vec};
 
 #[contract]
 pub struct TicketContract;
@@ -11,15 +17,22 @@ pub struct TicketContract;
 impl TicketContract {
     /// Initialize an event with its organizer.
     /// The organizer is automatically authorized to validate tickets.
-    pub fn init_event(env: Env, event_id: Symbol, organizer: Address) {
+    /// An initialized event cannot be claimed again by another organizer.
+    pub fn init_event(env: Env, event_id: Symbol, organizer: Address) -> Result<(), LumentixError> {
         organizer.require_auth();
 
+        if Self::event_auth(&env, &event_id).is_ok() {
+            return Err(LumentixError::EventAlreadyExists);
+        }
+
         let event_auth = EventAuth {
             event_id: event_id.clone(),
             organizer: organizer.clone(),
         };
 
-        env.storage().persistent().set(&event_id, &event_auth);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EventAuth(event_id.clone()), &event_auth);
 
         log!(
             &env,
@@ -27,25 +40,25 @@ impl TicketContract {
             event_id,
             organizer
         );
+
+        Ok(())
     }
 
     /// Add an authorized validator (gate agent) for an event.
     /// Only the event organizer can add validators.
-    pub fn add_validator(env: Env, event_id: Symbol, validator: Address) {
-        let event_auth: EventAuth = env
-            .storage()
-            .persistent()
-            .get(&event_id)
-            .expect("Event not found");
+    pub fn add_validator(
+        env: Env,
+        event_id: Symbol,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        let event_auth = Self::event_auth(&env, &event_id)?;
 
         event_auth.organizer.require_auth();
 
-        let validator_key = ValidatorKey {
-            event_id: event_id.clone(),
-            validator: validator.clone(),
-        };
-
-        env.storage().persistent().set(&validator_key, &true);
+        env.storage().persistent().set(
+            &DataKey::Validator(event_id.clone(), validator.clone()),
+            &true,
+        );
 
         log!(
             &env,
@@ -53,25 +66,24 @@ impl TicketContract {
             event_id,
             validator
         );
+
+        Ok(())
     }
 
     /// Remove an authorized validator for an event.
     /// Only the event organizer can remove validators.
-    pub fn remove_validator(env: Env, event_id: Symbol, validator: Address) {
-        let event_auth: EventAuth = env
-            .storage()
-            .persistent()
-            .get(&event_id)
-            .expect("Event not found");
+    pub fn remove_validator(
+        env: Env,
+        event_id: Symbol,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        let event_auth = Self::event_auth(&env, &event_id)?;
 
         event_auth.organizer.require_auth();
 
-        let validator_key = ValidatorKey {
-            event_id: event_id.clone(),
-            validator: validator.clone(),
-        };
-
-        env.storage().persistent().remove(&validator_key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Validator(event_id.clone(), validator.clone()));
 
         log!(
             &env,
@@ -79,35 +91,107 @@ impl TicketContract {
             event_id,
             validator
         );
+
+        Ok(())
     }
 
     /// Check if an address is authorized to validate tickets for an event.
     pub fn is_authorized_validator(env: Env, event_id: Symbol, validator: Address) -> bool {
         // Check if this is the organizer
-        if let Some(event_auth) = env
-            .storage()
-            .persistent()
-            .get::<Symbol, EventAuth>(&event_id)
-        {
+        if let Ok(event_auth) = Self::event_auth(&env, &event_id) {
             if event_auth.organizer == validator {
                 return true;
             }
         }
 
         // Check if this is an authorized validator
-        let validator_key = ValidatorKey {
-            event_id,
-            validator,
-        };
-
         env.storage()
             .persistent()
-            .get::<ValidatorKey, bool>(&validator_key)
+            .get::<DataKey, bool>(&DataKey::Validator(event_id, validator))
             .unwrap_or(false)
     }
 
+    /// Opt an event in to (or out of) attendance badges minted at validation.
+    /// Only the event organizer can change the badge mode.
+    pub fn enable_attendance_badges(
+        env: Env,
+        event_id: Symbol,
+        enabled: bool,
+    ) -> Result<(), LumentixError> {
+        let event_auth = Self::event_auth(&env, &event_id)?;
+
+        event_auth.organizer.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::BadgesEnabled(event_id.clone()), &enabled);
+
+        log!(
+            &env,
+            "Attendance badges set: event={:?}, enabled={:?}",
+            event_id,
+            enabled
+        );
+
+        Ok(())
+    }
+
+    /// Get a page of the attendance badges held by an address, in minting order.
+    /// Returns up to `limit` badges (at most `MAX_PAGE_SIZE`) starting at index `start`.
+    pub fn get_badges(env: Env, holder: Address, start: u32, limit: u32) -> Vec<AttendanceBadge> {
+        let storage = env.storage().persistent();
+        let count: u32 = storage
+            .get(&DataKey::HolderBadgeCount(holder.clone()))
+            .unwrap_or(0);
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut badges = Vec::new(&env);
+        for index in start..end {
+            let event_id: Option<Symbol> =
+                storage.get(&DataKey::HolderBadge(holder.clone(), index));
+            if let Some(badge) =
+                event_id.and_then(|event_id| storage.get(&DataKey::Badge(event_id, holder.clone())))
+            {
+                badges.push_back(badge);
+            }
+        }
+        badges
+    }
+
+    /// Get a page of the addresses holding an event's attendance badge, in minting order.
+    /// Returns up to `limit` holders (at most `MAX_PAGE_SIZE`) starting at index `start`.
+    pub fn get_badge_holders(env: Env, event_id: Symbol, start: u32, limit: u32) -> Vec<Address> {
+        let storage = env.storage().persistent();
+        let count: u32 = storage
+            .get(&DataKey::BadgeHolderCount(event_id.clone()))
+            .unwrap_or(0);
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut holders = Vec::new(&env);
+        for index in start..end {
+            if let Some(holder) = storage.get(&DataKey::BadgeHolder(event_id.clone(), index)) {
+                holders.push_back(holder);
+            }
+        }
+        holders
+    }
+
     /// Issue a new ticket to an owner for a specific event.
-    pub fn issue_ticket(env: Env, ticket_id: Symbol, event_id: Symbol, owner: Address) -> Ticket {
+    /// Only the event organizer can issue tickets, and a ticket ID cannot be reused.
+    pub fn issue_ticket(
+        env: Env,
+        ticket_id: Symbol,
+        event_id: Symbol,
+        owner: Address,
+    ) -> Result<Ticket, LumentixError> {
+        let event_auth = Self::event_auth(&env, &event_id)?;
+
+        event_auth.organizer.require_auth();
+
+        if Self::load_ticket(&env, &ticket_id).is_ok() {
+            return Err(LumentixError::TicketAlreadyExists);
+        }
+
         let ticket = Ticket {
             id: ticket_id.clone(),
             event_id,
@@ -115,80 +199,120 @@ impl TicketContract {
             is_used: false,
         };
 
-        env.storage().persistent().set(&ticket_id, &ticket);
+        Self::save_ticket(&env, &ticket);
 
         log!(&env, "Ticket issued: id={:?}, owner={:?}", ticket_id, owner);
 
-        ticket
+        Ok(ticket)
     }
 
     /// Retrieve a ticket by its ID. Returns None if not found.
     pub fn get_ticket(env: Env, ticket_id: Symbol) -> Option<Ticket> {
-        env.storage().persistent().get::<Symbol, Ticket>(&ticket_id)
-    }
-
-    /// Returns true if the given address is the current owner of the ticket.
-    pub fn is_ticket_owner(env: Env, ticket_id: Symbol, address: Address) -> bool {
-        let ticket = env
-            .storage()
+        env.storage()
             .persistent()
             .get::<DataKey, Ticket>(&DataKey::Ticket(ticket_id))
-            .expect("Ticket not found");
+    }
 
-        ticket.owner == address
+    /// Returns true if the given address is the current owner of the ticket.
+    pub fn is_ticket_owner(
+        env: Env,
+        ticket_id: Symbol,
+        address: Address,
+    ) -> Result<bool, LumentixError> {
+        let ticket = Self::load_ticket(&env, &ticket_id)?;
+
+        Ok(ticket.owner == address)
     }
 
     /// Returns the current owner and used status of a ticket as a tuple (Address, bool).
-    pub fn get_ticket_status(env: Env, ticket_id: Symbol) -> (Address, bool) {
-        let ticket = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Ticket>(&DataKey::Ticket(ticket_id))
-            .expect("Ticket not found");
+    pub fn get_ticket_status(
+        env: Env,
+        ticket_id: Symbol,
+    ) -> Result<(Address, bool), LumentixError> {
+        let ticket = Self::load_ticket(&env, &ticket_id)?;
 
-        (ticket.owner, ticket.is_used)
+        Ok((ticket.owner, ticket.is_used))
     }
 
-    /// Mark a ticket as used.
-    pub fn mark_ticket_used(env: Env, ticket_id: Symbol) {
-        let mut ticket: Ticket = env
-            .storage()
-            .persistent()
-            .get(&ticket_id)
-            .expect("Ticket not found");
+    /// Mark a ticket as used, minting the holder's attendance badge if the event opted in.
+    /// Only the event organizer or an authorized validator can mark tickets.
+    pub fn mark_ticket_used(
+        env: Env,
+        ticket_id: Symbol,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        validator.require_auth();
+
+        let mut ticket = Self::load_ticket(&env, &ticket_id)?;
+
+        if !Self::is_authorized_validator(env.clone(), ticket.event_id.clone(), validator) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if ticket.is_used {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
 
         ticket.is_used = true;
-        env.storage().persistent().set(&ticket_id, &ticket);
+        Self::save_ticket(&env, &ticket);
+
+        Self::mint_badge(&env, &ticket.event_id, &ticket.owner);
+
+        Ok(())
     }
 
     /// Transfer a ticket from the current owner to a new owner.
-    pub fn transfer_ticket(env: Env, ticket_id: Symbol, from: Address, to: Address) {
+    pub fn transfer_ticket(
+        env: Env,
+        ticket_id: Symbol,
+        from: Address,
+        to: Address,
+    ) -> Result<(), LumentixError> {
         from.require_auth();
 
-        let mut ticket: Ticket = env
-            .storage()
-            .persistent()
-            .get(&ticket_id)
-            .expect("Ticket not found");
+        let mut ticket = Self::load_ticket(&env, &ticket_id)?;
 
         if ticket.owner != from {
-            panic!("Unauthorized: not the ticket owner");
+            return Err(LumentixError::Unauthorized);
         }
 
         ticket.owner = to.clone();
-        env.storage().persistent().set(&ticket_id, &ticket);
+        Self::save_ticket(&env, &ticket);
 
         TransferEvent::emit(&env, ticket_id.clone(), from, to);
 
         log!(&env, "Ticket transferred: id={:?}", ticket_id);
+
+        Ok(())
     }
 
     /// Configure the multi-sig escrow signers and threshold for an event.
-    pub fn set_escrow_signers(env: Env, event_id: Symbol, signers: Vec<Address>, threshold: u32) {
+    /// Only the event organizer can configure signers, and not while any
+    /// open release proposals have approvals. Remaining open proposals are
+    /// cancelled, since their votes were cast under the old signer set.
+    pub fn set_escrow_signers(
+        env: Env,
+        event_id: Symbol,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), LumentixError> {
+        let event_auth = Self::event_auth(&env, &event_id)?;
+
+        event_auth.organizer.require_auth();
+
         if threshold == 0 || threshold > signers.len() {
-            panic!("Invalid threshold: must be > 0 and <= number of signers");
+            return Err(LumentixError::InvalidThreshold);
         }
 
+        if Self::get_open_proposals(env.clone(), event_id.clone())
+            .iter()
+            .any(|proposal| !proposal.approvals.is_empty())
+        {
+            return Err(LumentixError::ApprovalsOutstanding);
+        }
+
+        Self::cancel_open_proposals(&env, &event_id);
+
         let config = EscrowConfig {
             event_id: event_id.clone(),
             signers,
@@ -200,49 +324,272 @@ impl TicketContract {
             .set(&DataKey::EscrowConfig(event_id.clone()), &config);
 
         log!(&env, "Escrow signers set for event={:?}", event_id);
+
+        Ok(())
     }
 
-    /// Approve the release of escrow funds for an event.
-    pub fn approve_release(env: Env, event_id: Symbol, signer: Address) {
-        signer.require_auth();
+    /// Deposit tokens into an event's escrow.
+    /// The first deposit fixes the event's escrow token; later deposits must use it.
+    pub fn deposit_escrow(
+        env: Env,
+        event_id: Symbol,
+        from: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, LumentixError> {
+        from.require_auth();
+
+        if amount <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
 
-        let config = env
+        Self::event_auth(&env, &event_id)?;
+
+        let token_key = DataKey::EscrowToken(event_id.clone());
+        match env
             .storage()
             .persistent()
-            .get::<DataKey, EscrowConfig>(&DataKey::EscrowConfig(event_id.clone()))
-            .expect("Escrow config not found");
+            .get::<DataKey, Address>(&token_key)
+        {
+            Some(escrow_token) if escrow_token != token => {
+                return Err(LumentixError::InvalidAddress);
+            }
+            Some(_) => {}
+            None => env.storage().persistent().set(&token_key, &token),
+        }
+
+        token::Client::new(&env, &token).transfer(&from, env.current_contract_address(), &amount);
+
+        let balance = Self::escrow_balance(&env, &event_id) + amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowBalance(event_id.clone()), &balance);
+
+        // Emit EscrowDeposited event
+        EscrowDeposited::emit(&env, event_id, from, amount);
 
-        if !config.signers.iter().any(|s| s == signer) {
-            panic!("Unauthorized: signer not in escrow group");
+        Ok(balance)
+    }
+
+    /// Get the token balance held in escrow for an event.
+    pub fn get_escrow_balance(env: Env, event_id: Symbol) -> i128 {
+        Self::escrow_balance(&env, &event_id)
+    }
+
+    /// Propose releasing `amount` of an event's escrow to `destination`.
+    /// Only escrow signers can propose; the proposal can be approved until `expires_at`,
+    /// which must fall within the longest TTL a proposal can be stored for.
+    pub fn propose_release(
+        env: Env,
+        event_id: Symbol,
+        proposer: Address,
+        destination: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<u64, LumentixError> {
+        proposer.require_auth();
+
+        if amount <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        // The proposal must stay in storage until it expires
+        let now = env.ledger().timestamp();
+        let max_lifetime = env.storage().max_ttl() as u64 * SECONDS_PER_LEDGER;
+        if expires_at <= now || expires_at > now.saturating_add(max_lifetime) {
+            return Err(LumentixError::InvalidTimeRange);
         }
 
-        let key = DataKey::EscrowApproval(event_id.clone(), signer.clone());
-        env.storage().temporary().set(&key, &true);
+        let config = Self::escrow_config(&env, &event_id)?;
+        if !config.signers.contains(&proposer) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let proposal_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProposalCounter)
+            .unwrap_or(0)
+            + 1;
         env.storage()
-            .temporary()
-            .extend_ttl(&key, TEMPORARY_LIFETIME, TEMPORARY_LIFETIME);
+            .persistent()
+            .set(&DataKey::ProposalCounter, &proposal_id);
 
-        log!(
-            &env,
-            "Release approved: event={:?}, signer={:?}",
-            event_id,
-            signer
-        );
+        let proposal = ReleaseProposal {
+            id: proposal_id,
+            event_id: event_id.clone(),
+            proposer: proposer.clone(),
+            destination: destination.clone(),
+            amount,
+            expires_at,
+            approvals: Vec::new(&env),
+            rejections: Vec::new(&env),
+            status: ProposalStatus::Open,
+        };
+        Self::save_proposal(&env, &proposal);
+
+        // Expired proposals can never be executed, so drop them from the open list
+        let mut open = Vec::new(&env);
+        for open_id in Self::open_proposal_ids(&env, &event_id).iter() {
+            if let Ok(open_proposal) = Self::load_proposal(&env, open_id) {
+                if open_proposal.expires_at > now {
+                    open.push_back(open_id);
+                }
+            }
+        }
+        open.push_back(proposal_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OpenProposals(event_id.clone()), &open);
+
+        // Emit ProposalCreated event
+        ProposalCreated::emit(&env, proposal_id, event_id, destination, amount, expires_at);
+
+        Ok(proposal_id)
+    }
+
+    /// Approve an open release proposal. A signer that rejected it earlier
+    /// changes its vote.
+    pub fn approve_proposal(
+        env: Env,
+        proposal_id: u64,
+        signer: Address,
+    ) -> Result<(), LumentixError> {
+        signer.require_auth();
+
+        let mut proposal = Self::open_proposal(&env, proposal_id)?;
+        let config = Self::escrow_config(&env, &proposal.event_id)?;
+        if !config.signers.contains(&signer) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if let Some(index) = proposal.rejections.first_index_of(&signer) {
+            proposal.rejections.remove(index);
+        }
+        if !proposal.approvals.contains(&signer) {
+            proposal.approvals.push_back(signer.clone());
+        }
+        Self::save_proposal(&env, &proposal);
+
+        // Emit ProposalApproved event
+        ProposalApproved::emit(&env, proposal_id, signer);
+
+        Ok(())
     }
 
-    /// Revoke a previously given approval.
-    pub fn revoke_approval(env: Env, event_id: Symbol, signer: Address) {
+    /// Reject an open release proposal. A signer that approved it earlier
+    /// changes its vote. Once the threshold can no longer be reached the
+    /// proposal is closed as rejected.
+    pub fn reject_proposal(
+        env: Env,
+        proposal_id: u64,
+        signer: Address,
+    ) -> Result<(), LumentixError> {
         signer.require_auth();
 
-        let key = DataKey::EscrowApproval(event_id.clone(), signer.clone());
-        env.storage().temporary().remove(&key);
+        let mut proposal = Self::open_proposal(&env, proposal_id)?;
+        let config = Self::escrow_config(&env, &proposal.event_id)?;
+        if !config.signers.contains(&signer) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if let Some(index) = proposal.approvals.first_index_of(&signer) {
+            proposal.approvals.remove(index);
+        }
+        if !proposal.rejections.contains(&signer) {
+            proposal.rejections.push_back(signer.clone());
+        }
+        let rejections = Self::signer_votes(&config, &proposal.rejections);
+        if config.signers.len().saturating_sub(rejections) < config.threshold {
+            proposal.status = ProposalStatus::Rejected;
+            Self::close_proposal(&env, &proposal);
+        }
+        Self::save_proposal(&env, &proposal);
+
+        // Emit ProposalRejected event
+        ProposalRejected::emit(&env, proposal_id, signer);
+
+        Ok(())
+    }
+
+    /// Execute a release proposal once enough signers approved it, transferring
+    /// its amount from the event's escrow to its destination.
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), LumentixError> {
+        let mut proposal = Self::open_proposal(&env, proposal_id)?;
+        let config = Self::escrow_config(&env, &proposal.event_id)?;
+
+        if Self::signer_votes(&config, &proposal.approvals) < config.threshold {
+            return Err(LumentixError::ThresholdNotMet);
+        }
+
+        let balance = Self::escrow_balance(&env, &proposal.event_id);
+        if proposal.amount > balance {
+            return Err(LumentixError::InsufficientEscrow);
+        }
+
+        let token: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowToken(proposal.event_id.clone()))
+            .ok_or(LumentixError::InsufficientEscrow)?;
+
+        env.storage().persistent().set(
+            &DataKey::EscrowBalance(proposal.event_id.clone()),
+            &(balance - proposal.amount),
+        );
+        proposal.status = ProposalStatus::Executed;
+        Self::close_proposal(&env, &proposal);
+        Self::save_proposal(&env, &proposal);
+
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &proposal.destination,
+            &proposal.amount,
+        );
 
         log!(
             &env,
-            "Approval revoked: event={:?}, signer={:?}",
-            event_id,
-            signer
+            "Escrow funds distributed: event={:?}, to={:?}, amount={:?}",
+            proposal.event_id,
+            proposal.destination,
+            proposal.amount
         );
+
+        // Emit EscrowDistributed event
+        EscrowDistributed::emit(
+            &env,
+            proposal.event_id,
+            proposal.destination,
+            proposal.amount,
+        );
+
+        Ok(())
+    }
+
+    /// Get a release proposal by its ID.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<ReleaseProposal, LumentixError> {
+        Self::load_proposal(&env, proposal_id)
+    }
+
+    /// Get the signers that approved a release proposal.
+    pub fn get_proposal_approvals(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<Vec<Address>, LumentixError> {
+        Ok(Self::load_proposal(&env, proposal_id)?.approvals)
+    }
+
+    /// Get the release proposals of an event that are open and not yet expired.
+    pub fn get_open_proposals(env: Env, event_id: Symbol) -> Vec<ReleaseProposal> {
+        let now = env.ledger().timestamp();
+        let mut proposals = Vec::new(&env);
+        for proposal_id in Self::open_proposal_ids(&env, &event_id).iter() {
+            if let Ok(proposal) = Self::load_proposal(&env, proposal_id) {
+                if proposal.expires_at > now {
+                    proposals.push_back(proposal);
+                }
+            }
+        }
+        proposals
     }
 
     /// Validate a ticket at event check-in.
@@ -252,17 +599,20 @@ impl TicketContract {
     /// 2. Checks the ticket exists and is_used == false
     /// 3. Sets is_used = true in contract storage
     /// 4. Emits a CheckInEvent
-    pub fn validate_ticket(env: Env, ticket_id: Symbol, validator: Address) -> Ticket {
+    /// 5. Mints an attendance badge for the holder if the event opted in
+    pub fn validate_ticket(
+        env: Env,
+        ticket_id: Symbol,
+        validator: Address,
+    ) -> Result<Ticket, LumentixError> {
+        validator.require_auth();
+
         // 1. Get the ticket - must exist
-        let ticket: Ticket = env
-            .storage()
-            .persistent()
-            .get(&ticket_id)
-            .expect("Ticket not found");
+        let ticket = Self::load_ticket(&env, &ticket_id)?;
 
         // 2. Check if ticket is already used (prevent double check-in)
         if ticket.is_used {
-            panic!("Ticket has already been used");
+            return Err(LumentixError::TicketAlreadyUsed);
         }
 
         // 3. Verify validator is authorized for this event
@@ -270,7 +620,7 @@ impl TicketContract {
             Self::is_authorized_validator(env.clone(), ticket.event_id.clone(), validator.clone());
 
         if !is_authorized {
-            panic!("Unauthorized: validator is not authorized for this event");
+            return Err(LumentixError::Unauthorized);
         }
 
         // 4. Mark ticket as used
@@ -281,9 +631,7 @@ impl TicketContract {
             is_used: true,
         };
 
-        env.storage()
-            .persistent()
-            .set(&ticket_id, &validated_ticket);
+        Self::save_ticket(&env, &validated_ticket);
 
         // 5. Emit CheckInEvent
         CheckInEvent::emit(
@@ -293,6 +641,9 @@ impl TicketContract {
             ticket.event_id.clone(),
         );
 
+        // 6. Mint attendance badge
+        Self::mint_badge(&env, &ticket.event_id, &ticket.owner);
+
         log!(
             &env,
             "Ticket validated: id={:?}, validator={:?}, event={:?}",
@@ -301,43 +652,167 @@ impl TicketContract {
             ticket.event_id
         );
 
-        validated_ticket
+        Ok(validated_ticket)
     }
+}
 
-    /// Check if the threshold is met and execute fund distribution.
-    pub fn distribute_escrow(env: Env, event_id: Symbol, destination: Address) {
-        let config = env
-            .storage()
+impl TicketContract {
+    /// Load an event's organizer record.
+    fn event_auth(env: &Env, event_id: &Symbol) -> Result<EventAuth, LumentixError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventAuth(event_id.clone()))
+            .ok_or(LumentixError::EventNotFound)
+    }
+
+    /// Load a ticket by its ID.
+    fn load_ticket(env: &Env, ticket_id: &Symbol) -> Result<Ticket, LumentixError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Ticket(ticket_id.clone()))
+            .ok_or(LumentixError::TicketNotFound)
+    }
+
+    fn save_ticket(env: &Env, ticket: &Ticket) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Ticket(ticket.id.clone()), ticket);
+    }
+
+    /// Token balance held in escrow for an event.
+    fn escrow_balance(env: &Env, event_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowBalance(event_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Load a release proposal by its ID.
+    fn load_proposal(env: &Env, proposal_id: u64) -> Result<ReleaseProposal, LumentixError> {
+        env.storage()
             .persistent()
-            .get::<DataKey, EscrowConfig>(&DataKey::EscrowConfig(event_id.clone()))
-            .expect("Escrow config not found");
-
-        let mut approval_count = 0;
-        for signer in config.signers.iter() {
-            let key = DataKey::EscrowApproval(event_id.clone(), signer.clone());
-            if env.storage().temporary().has(&key) {
-                approval_count += 1;
-                env.storage()
-                    .temporary()
-                    .extend_ttl(&key, TEMPORARY_LIFETIME, TEMPORARY_LIFETIME);
+            .get(&DataKey::Proposal(proposal_id))
+            .ok_or(LumentixError::ProposalNotFound)
+    }
+
+    /// Load a release proposal that can still be voted on or executed.
+    fn open_proposal(env: &Env, proposal_id: u64) -> Result<ReleaseProposal, LumentixError> {
+        let proposal = Self::load_proposal(env, proposal_id)?;
+        if proposal.status != ProposalStatus::Open {
+            return Err(LumentixError::ProposalNotOpen);
+        }
+        if env.ledger().timestamp() >= proposal.expires_at {
+            return Err(LumentixError::ProposalExpired);
+        }
+        Ok(proposal)
+    }
+
+    /// Store a proposal, keeping it and its approvals live until it expires.
+    fn save_proposal(env: &Env, proposal: &ReleaseProposal) {
+        let key = DataKey::Proposal(proposal.id);
+        env.storage().persistent().set(&key, proposal);
+
+        let remaining = proposal
+            .expires_at
+            .saturating_sub(env.ledger().timestamp())
+            .div_ceil(SECONDS_PER_LEDGER);
+        let ledgers = (remaining.min(u32::MAX as u64) as u32)
+            .max(PERSISTENT_LIFETIME)
+            .min(env.storage().max_ttl());
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, ledgers, ledgers);
+    }
+
+    fn open_proposal_ids(env: &Env, event_id: &Symbol) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenProposals(event_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Drop a proposal from its event's open list once executed or rejected.
+    fn close_proposal(env: &Env, proposal: &ReleaseProposal) {
+        let mut open = Self::open_proposal_ids(env, &proposal.event_id);
+        if let Some(index) = open.first_index_of(proposal.id) {
+            open.remove(index);
+            env.storage()
+                .persistent()
+                .set(&DataKey::OpenProposals(proposal.event_id.clone()), &open);
+        }
+    }
+
+    /// Cancel every open proposal of an event and clear its open list.
+    fn cancel_open_proposals(env: &Env, event_id: &Symbol) {
+        for proposal_id in Self::open_proposal_ids(env, event_id).iter() {
+            if let Ok(mut proposal) = Self::load_proposal(env, proposal_id) {
+                proposal.status = ProposalStatus::Cancelled;
+                Self::save_proposal(env, &proposal);
             }
         }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OpenProposals(event_id.clone()));
+    }
 
-        if approval_count < config.threshold {
-            panic!("Threshold not met for escrow release");
+    /// Count the votes cast by the event's current signers.
+    fn signer_votes(config: &EscrowConfig, votes: &Vec<Address>) -> u32 {
+        votes
+            .iter()
+            .filter(|voter| config.signers.contains(voter))
+            .count() as u32
+    }
+
+    /// Load an event's escrow signer configuration.
+    fn escrow_config(env: &Env, event_id: &Symbol) -> Result<EscrowConfig, LumentixError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowConfig(event_id.clone()))
+            .ok_or(LumentixError::EscrowConfigNotFound)
+    }
+
+    /// Mint the event's attendance badge for a holder, once, if the event opted in.
+    fn mint_badge(env: &Env, event_id: &Symbol, holder: &Address) {
+        let storage = env.storage().persistent();
+
+        let enabled: bool = storage
+            .get(&DataKey::BadgesEnabled(event_id.clone()))
+            .unwrap_or(false);
+        let badge_key = DataKey::Badge(event_id.clone(), holder.clone());
+        if !enabled || storage.has(&badge_key) {
+            return;
         }
 
-        log!(
-            &env,
-            "Escrow funds distributed: event={:?}, to={:?}",
+        let badge = AttendanceBadge {
+            event_id: event_id.clone(),
+            holder: holder.clone(),
+            checked_in_at: env.ledger().timestamp(),
+        };
+        Self::set_badge_entry(env, &badge_key, &badge);
+
+        // Each index entry is its own key so minting never rewrites a growing list
+        let holder_count_key = DataKey::HolderBadgeCount(holder.clone());
+        let holder_count: u32 = storage.get(&holder_count_key).unwrap_or(0);
+        Self::set_badge_entry(
+            env,
+            &DataKey::HolderBadge(holder.clone(), holder_count),
             event_id,
-            destination
         );
+        Self::set_badge_entry(env, &holder_count_key, &(holder_count + 1));
+
+        let holders_count_key = DataKey::BadgeHolderCount(event_id.clone());
+        let holders_count: u32 = storage.get(&holders_count_key).unwrap_or(0);
+        Self::set_badge_entry(
+            env,
+            &DataKey::BadgeHolder(event_id.clone(), holders_count),
+            holder,
+        );
+        Self::set_badge_entry(env, &holders_count_key, &(holders_count + 1));
+    }
 
-        // Clear approvals after successful distribution
-        for signer in config.signers.iter() {
-            let key = DataKey::EscrowApproval(event_id.clone(), signer.clone());
-            env.storage().temporary().remove(&key);
-        }
+    fn set_badge_entry<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        let storage = env.storage().persistent();
+        storage.set(key, value);
+        storage.extend_ttl(key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
     }
 }
@@ -65,4 +65,130 @@ pub enum LumentixError {
 
     /// No platform fees available to withdraw
     NoPlatformFees = 20,
+
+    /// Payment amount exceeds the required price
+    Overpayment = 21,
+
+    /// Platform balance is insufficient to return a ticket's platform fee
+    InsufficientPlatformBalance = 22,
+
+    /// Bundle with the specified ID does not exist
+    BundleNotFound = 23,
+
+    /// Bundle events and weights are empty, mismatched, duplicated or zero-weighted
+    InvalidBundle = 24,
+
+    /// Session with the specified ID does not exist for the event
+    SessionNotFound = 25,
+
+    /// No session of the event is running at the current time
+    SessionNotActive = 26,
+
+    /// Ticket does not grant admission to the current session
+    SessionNotGranted = 27,
+
+    /// Re-entry is not enabled for this event
+    ReentryNotAllowed = 28,
+
+    /// Ticket is not currently checked in
+    TicketNotCheckedIn = 29,
+
+    /// Check-in attempted outside the event's check-in window
+    OutsideCheckInWindow = 30,
+
+    /// Ticket holder has not registered an entry pass key
+    PassKeyNotRegistered = 31,
+
+    /// Signed scan is not newer than the last accepted scan, or is dated in the future
+    StaleScan = 32,
+
+    /// Ticket does not belong to the specified event
+    TicketEventMismatch = 33,
+
+    /// Event tickets are soulbound and cannot be transferred
+    TransferNotAllowed = 34,
+
+    /// Transfer cutoff before the event start has passed
+    TransferCutoffPassed = 35,
+
+    /// Event tickets can only be transferred with organizer approval
+    TransferRequiresApproval = 36,
+
+    /// Ticket already has a pending transfer request
+    TransferRequestExists = 37,
+
+    /// No pending transfer request for the ticket
+    TransferRequestNotFound = 38,
+
+    /// No unclaimed gift for the claim code
+    GiftNotFound = 39,
+
+    /// Gift claim period has expired
+    GiftExpired = 40,
+
+    /// Gift can only be reclaimed after it expires or its event is cancelled
+    GiftNotExpired = 41,
+
+    /// A gift with the same claim code already exists
+    GiftCodeExists = 42,
+
+    /// Too many tags, or duplicate tags, on an event
+    InvalidTags = 43,
+
+    /// Event series does not exist
+    SeriesNotFound = 44,
+
+    /// Every occurrence of the event series has already been instantiated
+    SeriesExhausted = 45,
+
+    /// Event still holds escrow or has tickets awaiting refund
+    EventNotSettled = 46,
+
+    /// Escrow threshold must be greater than zero and at most the number of signers
+    InvalidThreshold = 47,
+
+    /// No escrow signers configured for the event
+    EscrowConfigNotFound = 48,
+
+    /// Not enough signer approvals to release escrow
+    ThresholdNotMet = 49,
+
+    /// Escrow signers cannot change while release approvals are outstanding
+    ApprovalsOutstanding = 50,
+
+    /// An event with the specified ID has already been initialized
+    EventAlreadyExists = 51,
+
+    /// Escrow release proposal with the specified ID does not exist
+    ProposalNotFound = 52,
+
+    /// Escrow release proposal has expired
+    ProposalExpired = 53,
+
+    /// Escrow release proposal has already been executed or rejected
+    ProposalNotOpen = 54,
+
+    /// Signer has no outstanding escrow approval for the event
+    EscrowApprovalNotFound = 55,
+
+    /// Sessions cannot change once tickets are sold or checked in
+    SessionsLocked = 56,
+
+    /// Gift claim was not committed in an earlier ledger
+    GiftClaimNotCommitted = 57,
+
+    /// Storage migration from the legacy layout has not completed yet
+    MigrationPending = 58,
+
+    /// Event is still referenced by validators, a bundle, a series or an unclaimed gift
+    EventInUse = 59,
+
+    /// Keeper page does not continue the event's unfinished keeper run
+    InvalidKeepAliveCursor = 60,
+
+    /// A ticket with the specified ID has already been issued
+    TicketAlreadyExists = 61,
+
+    /// A legacy record does not have the original layout's shape
+    InvalidLegacyRecord = 62,
 }
@@ -1,6 +1,6 @@
 #![allow(deprecated)]
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 /// A type for transfer of event
 pub struct TransferEvent;
@@ -12,6 +12,65 @@ impl TransferEvent {
     }
 }
 
+/// Event emitted when funds are deposited into an event's escrow
+pub struct EscrowDeposited;
+
+impl EscrowDeposited {
+    pub fn emit(env: &Env, event_id: Symbol, from: Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("escdep"), event_id), (from, amount));
+    }
+}
+
+/// Event emitted when approved escrow funds are sent to their destination
+pub struct EscrowDistributed;
+
+impl EscrowDistributed {
+    pub fn emit(env: &Env, event_id: Symbol, destination: Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("escdist"), event_id), (destination, amount));
+    }
+}
+
+/// Event emitted when an escrow release is proposed
+pub struct ProposalCreated;
+
+impl ProposalCreated {
+    pub fn emit(
+        env: &Env,
+        proposal_id: u64,
+        event_id: Symbol,
+        destination: Address,
+        amount: i128,
+        expires_at: u64,
+    ) {
+        env.events().publish(
+            (symbol_short!("propnew"), event_id),
+            (proposal_id, destination, amount, expires_at),
+        );
+    }
+}
+
+/// Event emitted when a signer approves an escrow release proposal
+pub struct ProposalApproved;
+
+impl ProposalApproved {
+    pub fn emit(env: &Env, proposal_id: u64, signer: Address) {
+        env.events()
+            .publish((symbol_short!("propok"), proposal_id), signer);
+    }
+}
+
+/// Event emitted when a signer rejects an escrow release proposal
+pub struct ProposalRejected;
+
+impl ProposalRejected {
+    pub fn emit(env: &Env, proposal_id: u64, signer: Address) {
+        env.events()
+            .publish((symbol_short!("proprej"), proposal_id), signer);
+    }
+}
+
 /// Event emitted when a ticket is checked in (validated)
 pub struct CheckInEvent;
 
@@ -54,6 +113,34 @@ impl EventCreated {
     }
 }
 
+/// Event emitted when the capacity or price of a published event changes
+pub struct EventSalesUpdated;
+
+impl EventSalesUpdated {
+    #[allow(clippy::too_many_arguments)]
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        organizer: Address,
+        old_max_tickets: u32,
+        new_max_tickets: u32,
+        old_ticket_price: i128,
+        new_ticket_price: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("evtsales"), event_id),
+            (
+                event_id,
+                organizer,
+                old_max_tickets,
+                new_max_tickets,
+                old_ticket_price,
+                new_ticket_price,
+            ),
+        );
+    }
+}
+
 /// Event emitted when platform fee is updated
 pub struct PlatformFeeUpdated;
 
@@ -66,6 +153,21 @@ impl PlatformFeeUpdated {
     }
 }
 
+/// Event emitted when the refund policy is updated
+pub struct RefundPolicyUpdated;
+
+impl RefundPolicyUpdated {
+    pub fn emit(
+        env: &Env,
+        admin: Address,
+        old_policy: crate::types::RefundPolicy,
+        new_policy: crate::types::RefundPolicy,
+    ) {
+        env.events()
+            .publish((symbol_short!("rfndpol"),), (admin, old_policy, new_policy));
+    }
+}
+
 /// Event emitted when an organizer cancels a published event.
 pub struct EventCancelled;
 
@@ -216,10 +318,19 @@ impl TicketUsed {
 pub struct TicketRefunded;
 
 impl TicketRefunded {
-    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, buyer: Address, refund_amount: i128) {
+    /// `fee_shortfall` is the part of the platform fee owed under the FullAmount
+    /// policy that could not be returned because the fees were already withdrawn
+    pub fn emit(
+        env: &Env,
+        ticket_id: u64,
+        event_id: u64,
+        buyer: Address,
+        refund_amount: i128,
+        fee_shortfall: i128,
+    ) {
         env.events().publish(
             (symbol_short!("tktrefnd"),),
-            (ticket_id, event_id, buyer, refund_amount),
+            (ticket_id, event_id, buyer, refund_amount, fee_shortfall),
         );
     }
 }
@@ -268,3 +379,302 @@ impl FundsWithdrawn {
         );
     }
 }
+
+/// Event emitted when an organizer creates a bundle (season pass)
+pub struct BundleCreated;
+
+impl BundleCreated {
+    pub fn emit(
+        env: &Env,
+        bundle_id: u64,
+        organizer: Address,
+        event_ids: Vec<u64>,
+        price: i128,
+        max_passes: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("bndlcrt"),),
+            (bundle_id, organizer, event_ids, price, max_passes),
+        );
+    }
+}
+
+/// Event emitted when a bundle is purchased, listing the ticket minted for each event
+pub struct BundlePurchased;
+
+impl BundlePurchased {
+    pub fn emit(
+        env: &Env,
+        bundle_id: u64,
+        buyer: Address,
+        ticket_ids: Vec<u64>,
+        amount: i128,
+        platform_fee: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("bndlbuy"),),
+            (bundle_id, buyer, ticket_ids, amount, platform_fee),
+        );
+    }
+}
+
+/// Event emitted when an organizer adds an admission session to an event
+pub struct SessionAdded;
+
+impl SessionAdded {
+    pub fn emit(env: &Env, event_id: u64, session_id: u32, start_time: u64, end_time: u64) {
+        env.events().publish(
+            (symbol_short!("sessadd"),),
+            (event_id, session_id, start_time, end_time),
+        );
+    }
+}
+
+/// Event emitted when a ticket checks in to a specific session
+pub struct SessionCheckedIn;
+
+impl SessionCheckedIn {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, session_id: u32) {
+        env.events().publish(
+            (symbol_short!("sesschkin"),),
+            (ticket_id, event_id, session_id),
+        );
+    }
+}
+
+/// Event emitted when a checked-in ticket scans out (re-entry mode)
+pub struct TicketScannedOut;
+
+impl TicketScannedOut {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, session_id: u32, caller: Address) {
+        env.events().publish(
+            (symbol_short!("tktscnout"),),
+            (ticket_id, event_id, session_id, caller),
+        );
+    }
+}
+
+/// Event emitted when a validator (gate agent) is authorized for an event
+pub struct ValidatorAdded;
+
+impl ValidatorAdded {
+    pub fn emit(env: &Env, event_id: u64, validator: Address) {
+        env.events()
+            .publish((symbol_short!("valadd"),), (event_id, validator));
+    }
+}
+
+/// Event emitted when a validator (gate agent) is removed from an event
+pub struct ValidatorRemoved;
+
+impl ValidatorRemoved {
+    pub fn emit(env: &Env, event_id: u64, validator: Address) {
+        env.events()
+            .publish((symbol_short!("valrm"),), (event_id, validator));
+    }
+}
+
+/// Event emitted when an attendance badge is minted at check-in
+pub struct BadgeMinted;
+
+impl BadgeMinted {
+    pub fn emit(env: &Env, holder: Address, event_id: u64, ticket_id: u64, checked_in_at: u64) {
+        env.events().publish(
+            (symbol_short!("badge"),),
+            (holder, event_id, ticket_id, checked_in_at),
+        );
+    }
+}
+
+/// Event emitted when a ticket's single-ticket approval is set or cleared
+pub struct TicketApproved;
+
+impl TicketApproved {
+    pub fn emit(env: &Env, ticket_id: u64, owner: Address, approved: Option<Address>) {
+        env.events().publish(
+            (symbol_short!("tktapprv"), ticket_id),
+            (ticket_id, owner, approved),
+        );
+    }
+}
+
+/// Event emitted when an operator is approved or revoked for all of an owner's tickets
+pub struct ApprovalForAll;
+
+impl ApprovalForAll {
+    pub fn emit(env: &Env, owner: Address, operator: Address, approved: bool) {
+        env.events()
+            .publish((symbol_short!("apprvall"),), (owner, operator, approved));
+    }
+}
+
+/// Event emitted when a holder requests an organizer-approved transfer
+pub struct TransferRequested;
+
+impl TransferRequested {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, from: Address, to: Address) {
+        env.events().publish(
+            (symbol_short!("xferreq"), ticket_id),
+            (ticket_id, event_id, from, to),
+        );
+    }
+}
+
+/// Event emitted when a transfer request is approved and executed
+pub struct TransferRequestApproved;
+
+impl TransferRequestApproved {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, approver: Address) {
+        env.events().publish(
+            (symbol_short!("xferok"), ticket_id),
+            (ticket_id, event_id, approver),
+        );
+    }
+}
+
+/// Event emitted when a transfer request is rejected
+pub struct TransferRequestRejected;
+
+impl TransferRequestRejected {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, approver: Address) {
+        env.events().publish(
+            (symbol_short!("xferrej"), ticket_id),
+            (ticket_id, event_id, approver),
+        );
+    }
+}
+
+/// Event emitted when a holder cancels their transfer request
+pub struct TransferRequestCancelled;
+
+impl TransferRequestCancelled {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, holder: Address) {
+        env.events().publish(
+            (symbol_short!("xfercncl"), ticket_id),
+            (ticket_id, event_id, holder),
+        );
+    }
+}
+
+/// Event emitted when a gift ticket is purchased
+pub struct GiftPurchased;
+
+impl GiftPurchased {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, purchaser: Address, expires_at: u64) {
+        env.events().publish(
+            (symbol_short!("giftbuy"), ticket_id),
+            (ticket_id, event_id, purchaser, expires_at),
+        );
+    }
+}
+
+/// Event emitted when a gift ticket is claimed with its secret
+pub struct GiftClaimed;
+
+impl GiftClaimed {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, claimer: Address) {
+        env.events().publish(
+            (symbol_short!("giftclaim"), ticket_id),
+            (ticket_id, event_id, claimer),
+        );
+    }
+}
+
+/// Event emitted when the purchaser reclaims an unclaimed gift ticket
+pub struct GiftReclaimed;
+
+impl GiftReclaimed {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, purchaser: Address) {
+        env.events().publish(
+            (symbol_short!("giftback"), ticket_id),
+            (ticket_id, event_id, purchaser),
+        );
+    }
+}
+
+/// Event emitted when a recurring event series is created
+pub struct SeriesCreated;
+
+impl SeriesCreated {
+    pub fn emit(env: &Env, series_id: u64, organizer: Address, interval: u64, count: u32) {
+        env.events().publish(
+            (symbol_short!("sercreate"),),
+            (series_id, organizer, interval, count),
+        );
+    }
+}
+
+/// Event emitted when a never-published draft event is deleted
+pub struct EventDeleted;
+
+impl EventDeleted {
+    pub fn emit(env: &Env, event_id: u64, organizer: Address) {
+        env.events()
+            .publish((symbol_short!("evtdel"),), (event_id, organizer));
+    }
+}
+
+/// Event emitted when a finished event is archived and its storage reclaimed
+pub struct EventArchived;
+
+impl EventArchived {
+    pub fn emit(env: &Env, event_id: u64, organizer: Address, tickets_removed: u32) {
+        env.events().publish(
+            (symbol_short!("evtarch"),),
+            (event_id, organizer, tickets_removed),
+        );
+    }
+}
+
+/// Event emitted when a signer set is attached to an event's escrow
+pub struct EscrowSignersSet;
+
+impl EscrowSignersSet {
+    pub fn emit(env: &Env, event_id: u64, caller: Address, signers: Vec<Address>, threshold: u32) {
+        env.events().publish(
+            (symbol_short!("escsigner"), event_id),
+            (caller, signers, threshold),
+        );
+    }
+}
+
+/// Event emitted when an escrow signer approves a release or withdrawal
+pub struct EscrowApproved;
+
+impl EscrowApproved {
+    pub fn emit(env: &Env, event_id: u64, signer: Address, recipient: Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("escapprv"), event_id),
+            (signer, recipient, amount),
+        );
+    }
+}
+
+/// Event emitted when an escrow signer revokes its approval
+pub struct EscrowApprovalRevoked;
+
+impl EscrowApprovalRevoked {
+    pub fn emit(env: &Env, event_id: u64, signer: Address) {
+        env.events()
+            .publish((symbol_short!("escrevoke"), event_id), signer);
+    }
+}
+
+/// Event emitted when records are moved from legacy tuple keys to typed keys
+pub struct StorageMigrated;
+
+impl StorageMigrated {
+    pub fn emit(env: &Env, admin: Address, entries_moved: u32) {
+        env.events()
+            .publish((symbol_short!("migrated"),), (admin, entries_moved));
+    }
+}
+
+/// Event emitted when a legacy record cannot be decoded and is left unmigrated
+pub struct LegacyRecordSkipped;
+
+impl LegacyRecordSkipped {
+    pub fn emit(env: &Env, phase: crate::types::MigrationPhase, id: u64) {
+        env.events().publish((symbol_short!("migskip"), phase), id);
+    }
+}
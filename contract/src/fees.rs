@@ -0,0 +1,181 @@
+use crate::error::LumentixError;
+use soroban_sdk::Vec;
+
+/// Basis points denominator (10000 bps = 100%)
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// How a single ticket payment is divided between the platform and event escrow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSplit {
+    pub platform_fee: i128,
+    pub escrow_amount: i128,
+}
+
+/// Validate that a payment covers the expected price exactly.
+/// Underpayment returns InsufficientFunds, overpayment returns Overpayment.
+pub fn validate_exact_payment(amount: i128, expected: i128) -> Result<(), LumentixError> {
+    if amount < expected {
+        return Err(LumentixError::InsufficientFunds);
+    }
+    if amount > expected {
+        return Err(LumentixError::Overpayment);
+    }
+    Ok(())
+}
+
+/// Calculate the platform fee for an amount, rounded down.
+pub fn platform_fee(amount: i128, fee_bps: u32) -> i128 {
+    (amount * fee_bps as i128) / BPS_DENOMINATOR
+}
+
+/// Split a single payment into platform fee and escrow amount.
+/// The two parts always sum to `amount`.
+pub fn split_payment(amount: i128, fee_bps: u32) -> FeeSplit {
+    let platform_fee = platform_fee(amount, fee_bps);
+    FeeSplit {
+        platform_fee,
+        escrow_amount: amount - platform_fee,
+    }
+}
+
+/// Split the ticket at `index` out of a batch of `quantity` tickets at `ticket_price` each.
+///
+/// The fee is computed once on the batch total, then divided evenly across tickets.
+/// Rounding dust (`total_fee % quantity`) is assigned one unit at a time to the
+/// lowest indexes, so summing every ticket's split yields exactly the batch totals.
+pub fn split_batch_ticket(ticket_price: i128, quantity: u32, fee_bps: u32, index: u32) -> FeeSplit {
+    let total_fee = platform_fee(ticket_price * quantity as i128, fee_bps);
+    let base_fee = total_fee / quantity as i128;
+    let dust = (total_fee % quantity as i128) as u32;
+
+    let platform_fee = if index < dust { base_fee + 1 } else { base_fee };
+    FeeSplit {
+        platform_fee,
+        escrow_amount: ticket_price - platform_fee,
+    }
+}
+
+/// Share of `amount` allocated to the part at `index`, proportional to `weights`.
+///
+/// Each part receives `amount * weight / total_weight` rounded down; the remaining
+/// dust is assigned one unit at a time to the lowest indexes, so the shares of all
+/// parts sum to exactly `amount`.
+pub fn weighted_share(amount: i128, weights: &Vec<u32>, index: u32) -> i128 {
+    let total_weight: i128 = weights.iter().map(|w| w as i128).sum();
+
+    let mut allocated = 0i128;
+    for weight in weights.iter() {
+        allocated += amount * weight as i128 / total_weight;
+    }
+    let dust = (amount - allocated) as u32;
+
+    let weight = weights.get(index).unwrap_or(0) as i128;
+    let share = amount * weight / total_weight;
+    if index < dust {
+        share + 1
+    } else {
+        share
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{vec, Env};
+
+    #[test]
+    fn test_validate_exact_payment() {
+        assert!(validate_exact_payment(100, 100).is_ok());
+        assert_eq!(
+            validate_exact_payment(99, 100),
+            Err(LumentixError::InsufficientFunds)
+        );
+        assert_eq!(
+            validate_exact_payment(101, 100),
+            Err(LumentixError::Overpayment)
+        );
+    }
+
+    #[test]
+    fn test_split_payment_sums_to_amount() {
+        assert_eq!(
+            split_payment(1000, 250),
+            FeeSplit {
+                platform_fee: 25,
+                escrow_amount: 975
+            }
+        );
+        assert_eq!(
+            split_payment(99, 250),
+            FeeSplit {
+                platform_fee: 2,
+                escrow_amount: 97
+            }
+        );
+        assert_eq!(
+            split_payment(100, 0),
+            FeeSplit {
+                platform_fee: 0,
+                escrow_amount: 100
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_batch_ticket_assigns_dust_to_first_tickets() {
+        // 3 x 33 = 99 at 10% -> total fee 9, which divides evenly
+        for i in 0..3 {
+            assert_eq!(split_batch_ticket(33, 3, 1000, i).platform_fee, 3);
+        }
+
+        // 3 x 37 = 111 at 10% -> total fee 11, base 3 with 2 units of dust
+        assert_eq!(split_batch_ticket(37, 3, 1000, 0).platform_fee, 4);
+        assert_eq!(split_batch_ticket(37, 3, 1000, 1).platform_fee, 4);
+        assert_eq!(split_batch_ticket(37, 3, 1000, 2).platform_fee, 3);
+    }
+
+    #[test]
+    fn test_split_batch_ticket_sums_to_batch_totals() {
+        for fee_bps in [0u32, 1, 250, 333, 1000, 9999, 10000] {
+            for quantity in 1..=10u32 {
+                let ticket_price = 97i128;
+                let total = ticket_price * quantity as i128;
+                let expected = split_payment(total, fee_bps);
+
+                let mut fee_sum = 0;
+                let mut escrow_sum = 0;
+                for i in 0..quantity {
+                    let split = split_batch_ticket(ticket_price, quantity, fee_bps, i);
+                    assert_eq!(split.platform_fee + split.escrow_amount, ticket_price);
+                    fee_sum += split.platform_fee;
+                    escrow_sum += split.escrow_amount;
+                }
+
+                assert_eq!(fee_sum, expected.platform_fee);
+                assert_eq!(escrow_sum, expected.escrow_amount);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weighted_share_sums_to_amount() {
+        let env = Env::default();
+
+        let even = vec![&env, 1u32, 1, 1];
+        assert_eq!(weighted_share(300, &even, 0), 100);
+        assert_eq!(weighted_share(300, &even, 2), 100);
+
+        // 100 over weights 1:1:1 -> 33 each with 1 unit of dust on the first part
+        assert_eq!(weighted_share(100, &even, 0), 34);
+        assert_eq!(weighted_share(100, &even, 1), 33);
+        assert_eq!(weighted_share(100, &even, 2), 33);
+
+        let uneven = vec![&env, 5u32, 3, 2, 7];
+        for amount in [1i128, 17, 99, 1000, 12345] {
+            let total: i128 = (0..uneven.len())
+                .map(|i| weighted_share(amount, &uneven, i))
+                .sum();
+            assert_eq!(total, amount);
+        }
+    }
+}
@@ -263,6 +263,8 @@ fn test_get_protocol_fee_fee_calculation_at_max() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
     client.purchase_ticket(&buyer, &event_id, &100i128);
@@ -291,6 +293,8 @@ fn test_get_protocol_fee_fee_calculation_at_zero() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
     client.purchase_ticket(&buyer, &event_id, &100i128);
@@ -318,6 +322,8 @@ fn test_deposit_funds_success_by_organizer() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let new_balance = client.deposit_funds(&organizer, &event_id, &500i128);
@@ -342,6 +348,8 @@ fn test_deposit_funds_success_by_admin() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let new_balance = client.deposit_funds(&admin, &event_id, &1000i128);
@@ -365,6 +373,8 @@ fn test_deposit_funds_accumulates() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     client.deposit_funds(&organizer, &event_id, &200i128);
@@ -391,6 +401,8 @@ fn test_deposit_funds_unauthorized() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let result = client.try_deposit_funds(&stranger, &event_id, &100i128);
@@ -414,6 +426,8 @@ fn test_deposit_funds_invalid_amount_zero() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let result = client.try_deposit_funds(&organizer, &event_id, &0i128);
@@ -437,6 +451,8 @@ fn test_deposit_funds_invalid_amount_negative() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let result = client.try_deposit_funds(&organizer, &event_id, &-1i128);
@@ -460,6 +476,8 @@ fn test_deposit_funds_cancelled_event_rejected() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
     client.cancel_event(&organizer, &event_id);
@@ -509,6 +527,8 @@ fn test_deposit_funds_emits_event() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     client.deposit_funds(&organizer, &event_id, &250i128);
@@ -536,6 +556,8 @@ fn test_deposit_funds_minimum_valid_amount() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let balance = client.deposit_funds(&organizer, &event_id, &1i128);
@@ -559,6 +581,8 @@ fn test_deposit_funds_large_amount() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Large but valid i128 value
@@ -585,6 +609,8 @@ fn test_deposit_funds_into_completed_event_allowed() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
     env.ledger().with_mut(|li| li.timestamp = 2001);
@@ -615,6 +641,8 @@ fn test_deposit_funds_maximum_i128_value() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Use maximum i128 value
@@ -642,6 +670,8 @@ fn test_deposit_funds_near_maximum_i128() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Use value close to max but with room for addition
@@ -673,6 +703,8 @@ fn test_deposit_funds_multiple_large_deposits() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Make several large deposits
@@ -708,6 +740,8 @@ fn test_deposit_funds_zero_and_negative_boundary() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Test zero amount (should fail)
@@ -741,6 +775,8 @@ fn test_deposit_funds_extreme_small_amounts() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Test with smallest positive amounts
@@ -772,6 +808,8 @@ fn test_deposit_funds_rapid_succession() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Make 100 small deposits rapidly
@@ -805,6 +843,8 @@ fn test_deposit_funds_alternating_large_small() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Alternate between large and small amounts
@@ -841,6 +881,8 @@ fn test_deposit_funds_state_persistence() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Make initial deposits
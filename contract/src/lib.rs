@@ -17,6 +17,7 @@ pub struct Tier {
 pub struct SponsorsContract;
 
 #[contractimpl]
+#[allow(deprecated)]
 impl SponsorsContract {
     pub fn register_sponsor_tier(
         env: Env,
@@ -93,6 +94,7 @@ impl SponsorsContract {
 }
 
 #[cfg(test)]
+#[allow(irrefutable_let_patterns)]
 mod sponsor_tests {
     use super::*;
     use soroban_sdk::{testutils::Address as _, testutils::Events, xdr, Env};
@@ -158,7 +160,7 @@ mod sponsor_tests {
         let events = env.events().all();
         assert_eq!(events.events().len(), 1);
 
-        let xdr_event = events.events().get(0).unwrap();
+        let xdr_event = events.events().first().unwrap();
         if let xdr::ContractEventBody::V0(body) = &xdr_event.body {
             assert_eq!(body.topics.len(), 1);
             if let xdr::ScVal::Symbol(topic_sym) = &body.topics[0] {
@@ -217,12 +219,16 @@ mod sponsor_tests {
     }
 }
 
+pub mod admission;
 mod contract;
 mod error;
 mod events;
+pub mod fees;
 pub mod lumentix_contract;
+mod migration;
 mod models;
 pub mod storage;
+mod transfer;
 pub mod types;
 pub mod validation;
 
@@ -242,5 +248,13 @@ pub use contract::TicketContract;
 pub use error::LumentixError;
 pub use events::{CheckInEvent, EventCancelled, TransferEvent};
 pub use lumentix_contract::LumentixContract;
-pub use models::{DataKey, EscrowConfig, EventAuth, Ticket as TicketModel, ValidatorKey};
-pub use types::{Event, EventStatus, Ticket as LumentixTicket};
+pub use models::{
+    DataKey, EscrowConfig, EventAuth, ProposalStatus, ReleaseProposal, Ticket as TicketModel,
+};
+pub use types::{
+    AdmissionState, AttendanceBadge, Bundle, CheckInResult, CheckInWindow, EscrowAction,
+    EscrowApproval, EscrowSigners, Event, EventCategory, EventSeries, EventStatus, EventSummary,
+    ExpiringEvent, Gift, KeepAliveProgress, MigrationPhase, MigrationProgress, Recurrence,
+    RefundPolicy, SeriesTemplate, Session, SignedScan, Ticket as LumentixTicket, TicketMetadata,
+    TransferPolicy, TransferRequest,
+};
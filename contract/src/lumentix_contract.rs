@@ -1,15 +1,34 @@
 #![allow(clippy::too_many_arguments)]
 
+use crate::admission;
 use crate::error::LumentixError;
 use crate::events::{
-    AdminChanged, EscrowReleased, EventCancelled, EventCompleted, EventCreated, EventStatusChanged,
-    EventUpdated, FundsDeposited, FundsWithdrawn, PlatformFeeUpdated, PlatformFeesWithdrawn, ProtocolFeeQueried,
-    TicketPurchased, TicketRefunded, TicketTransferred, TicketUsed,
+    AdminChanged, ApprovalForAll, BundleCreated, BundlePurchased, EscrowApprovalRevoked,
+    EscrowApproved, EscrowReleased, EscrowSignersSet, EventArchived, EventCancelled,
+    EventCompleted, EventCreated, EventDeleted, EventSalesUpdated, EventStatusChanged,
+    EventUpdated, FundsDeposited, FundsWithdrawn, GiftClaimed, GiftPurchased, GiftReclaimed,
+    PlatformFeeUpdated, PlatformFeesWithdrawn, ProtocolFeeQueried, RefundPolicyUpdated,
+    SeriesCreated, SessionAdded, SessionCheckedIn, StorageMigrated, TicketApproved,
+    TicketPurchased, TicketRefunded, TicketScannedOut, TicketUsed, TransferRequestApproved,
+    TransferRequestCancelled, TransferRequestRejected, TransferRequested, ValidatorAdded,
+    ValidatorRemoved,
+};
+use crate::fees;
+use crate::migration;
+use crate::storage::{self, EventIndex};
+use crate::transfer;
+use crate::types::{
+    AttendanceBadge, Bundle, CheckInResult, CheckInWindow, EscrowAction, EscrowApproval,
+    EscrowSigners, Event, EventCategory, EventSeries, EventStatus, EventSummary, ExpiringEvent,
+    Gift, KeepAliveProgress, MigrationProgress, Recurrence, RefundPolicy, SeriesTemplate, Session,
+    SignedScan, Ticket, TicketMetadata, TransferPolicy, TransferRequest, DEFAULT_CHECKIN_WINDOW,
+    DISPUTE_BUFFER, MAX_BUNDLE_EVENTS, MAX_EVENT_SESSIONS, MAX_EVENT_TAGS, MAX_PAGE_SIZE,
+    MAX_SERIES_OCCURRENCES, MAX_UPCOMING_DAYS, NO_SERIES, NO_SESSION, PERSISTENT_LIFETIME,
+    SECONDS_PER_DAY, SECONDS_PER_LEDGER, TEMPORARY_LIFETIME,
 };
-use crate::storage;
-use crate::types::{Event, EventStatus, Ticket, PERSISTENT_LIFETIME};
 use crate::validation;
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 
 #[contract]
 pub struct LumentixContract;
@@ -17,9 +36,10 @@ pub struct LumentixContract;
 #[contractimpl]
 impl LumentixContract {
     /// Initialize the contract with an admin address.
-    /// Can only be called once.
+    /// Can only be called once, and not on an upgraded deployment awaiting migration.
     pub fn initialize(env: Env, admin: Address) -> Result<(), LumentixError> {
-        if storage::is_initialized(&env) {
+        // An upgraded deployment keeps its admin until `migrate_storage` moves it
+        if storage::is_initialized(&env) || migration::has_legacy_config(&env) {
             return Err(LumentixError::AlreadyInitialized);
         }
 
@@ -41,13 +61,18 @@ impl LumentixContract {
         end_time: u64,
         ticket_price: i128,
         max_tickets: u32,
+        metadata_uri: Option<String>,
+        content_hash: Option<BytesN<32>>,
     ) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         organizer.require_auth();
 
         // Validate inputs
         validation::validate_string_not_empty(&name)?;
         validation::validate_string_not_empty(&description)?;
         validation::validate_string_not_empty(&location)?;
+        validation::validate_metadata(&metadata_uri, &content_hash)?;
         validation::validate_positive_amount(ticket_price)?;
         validation::validate_positive_capacity(max_tickets)?;
         validation::validate_time_range(start_time, end_time)?;
@@ -67,9 +92,19 @@ impl LumentixContract {
             max_tickets,
             tickets_sold: 0,
             status: EventStatus::Draft,
+            reentry_allowed: false,
+            checkin_window: DEFAULT_CHECKIN_WINDOW,
+            badges_enabled: false,
+            transfer_policy: TransferPolicy::Free,
+            metadata_uri,
+            content_hash,
+            category: EventCategory::Other,
+            tags: Vec::new(&env),
+            series_id: NO_SERIES,
         };
 
         storage::set_event(&env, event_id, &event);
+        storage::index_event(&env, &event);
 
         // Emit EventCreated event
         EventCreated::emit(
@@ -100,7 +135,11 @@ impl LumentixContract {
         end_time: u64,
         ticket_price: i128,
         max_tickets: u32,
+        metadata_uri: Option<String>,
+        content_hash: Option<BytesN<32>>,
     ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         organizer.require_auth();
 
         // Get the existing event
@@ -123,12 +162,21 @@ impl LumentixContract {
         validation::validate_positive_amount(ticket_price)?;
         validation::validate_positive_capacity(max_tickets)?;
         validation::validate_time_range(start_time, end_time)?;
+        validation::validate_metadata(&metadata_uri, &content_hash)?;
 
         // If max_tickets is being reduced, ensure it's not below tickets_sold
         if max_tickets < event.tickets_sold {
             return Err(LumentixError::CapacityExceeded);
         }
 
+        // Move the event to the start day index of its new start time
+        let old_day = event.start_time / SECONDS_PER_DAY;
+        let new_day = start_time / SECONDS_PER_DAY;
+        if old_day != new_day {
+            storage::remove_from_index(&env, &EventIndex::StartDay(old_day), event_id);
+            storage::add_to_index(&env, &EventIndex::StartDay(new_day), event_id);
+        }
+
         // Update event fields
         event.name = name.clone();
         event.description = description.clone();
@@ -137,6 +185,8 @@ impl LumentixContract {
         event.end_time = end_time;
         event.ticket_price = ticket_price;
         event.max_tickets = max_tickets;
+        event.metadata_uri = metadata_uri;
+        event.content_hash = content_hash;
 
         // Store updated event
         storage::set_event(&env, event_id, &event);
@@ -158,6 +208,139 @@ impl LumentixContract {
         Ok(())
     }
 
+    /// Update the capacity and price of a published event.
+    /// `max_tickets` may never drop below `tickets_sold`. A new `ticket_price`
+    /// applies to future sales only; sold tickets keep their recorded amounts.
+    /// Times, location and descriptive fields stay locked once published.
+    pub fn update_published_event(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        max_tickets: u32,
+        ticket_price: i128,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        validation::validate_positive_amount(ticket_price)?;
+        validation::validate_positive_capacity(max_tickets)?;
+        if max_tickets < event.tickets_sold {
+            return Err(LumentixError::CapacityExceeded);
+        }
+
+        let old_max_tickets = event.max_tickets;
+        let old_ticket_price = event.ticket_price;
+        event.max_tickets = max_tickets;
+        event.ticket_price = ticket_price;
+        storage::set_event(&env, event_id, &event);
+
+        // Emit EventSalesUpdated event
+        EventSalesUpdated::emit(
+            &env,
+            event_id,
+            organizer,
+            old_max_tickets,
+            max_tickets,
+            old_ticket_price,
+            ticket_price,
+        );
+
+        Ok(())
+    }
+
+    /// Delete a Draft event that was never published, reclaiming its storage.
+    /// Only the event organizer can delete it. Fails with EventInUse while the event
+    /// still has validators, belongs to a bundle or is an occurrence of a series.
+    pub fn delete_event(env: Env, organizer: Address, event_id: u64) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status != EventStatus::Draft {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        if storage::get_validator_count(&env, event_id) > 0
+            || storage::get_bundle_ref_count(&env, event_id) > 0
+            || event.series_id != NO_SERIES
+        {
+            return Err(LumentixError::EventInUse);
+        }
+
+        storage::unindex_event(&env, &event);
+        storage::remove_event(&env, event_id);
+
+        // Emit EventDeleted event
+        EventDeleted::emit(&env, event_id, organizer);
+
+        Ok(())
+    }
+
+    /// Archive a Completed or Cancelled event, removing the event and all of its
+    /// tickets from storage and keeping only a compact summary (see `get_archived_event`).
+    /// The event's escrow must be empty and, if cancelled, every ticket refunded.
+    /// Fails with EventInUse while the event still has validators or unclaimed gift
+    /// tickets, whose records could not be found again once it is gone.
+    /// Only the event organizer can archive it.
+    pub fn archive_event(env: Env, organizer: Address, event_id: u64) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status != EventStatus::Completed && event.status != EventStatus::Cancelled {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        if storage::get_escrow(&env, event_id)? != 0 {
+            return Err(LumentixError::EventNotSettled);
+        }
+
+        let tickets = Self::get_tickets_by_event(env.clone(), event_id)?;
+        if event.status == EventStatus::Cancelled && tickets.iter().any(|t| !t.refunded) {
+            return Err(LumentixError::EventNotSettled);
+        }
+        // The contract holds gift tickets until they are claimed or reclaimed
+        let contract = env.current_contract_address();
+        if storage::get_validator_count(&env, event_id) > 0
+            || tickets.iter().any(|t| t.owner == contract)
+        {
+            return Err(LumentixError::EventInUse);
+        }
+
+        let sessions = storage::get_sessions(&env, event_id);
+        for ticket in tickets.iter() {
+            // Refunded tickets already left their owner's balance
+            if !ticket.refunded {
+                storage::decrement_balance(&env, &ticket.owner);
+            }
+            storage::remove_ticket(&env, ticket.id, &sessions);
+        }
+
+        storage::unindex_event(&env, &event);
+        storage::remove_event(&env, event_id);
+        storage::set_archived_event(&env, &event.into());
+
+        // Emit EventArchived event
+        EventArchived::emit(&env, event_id, organizer, tickets.len());
+
+        Ok(())
+    }
+
     /// Update event status with validated transitions.
     /// Only the event organizer can update the status.
     /// Valid transitions: Draft -> Published, Published -> Cancelled, Published -> Completed (after end_time).
@@ -167,6 +350,8 @@ impl LumentixContract {
         new_status: EventStatus,
         caller: Address,
     ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         caller.require_auth();
 
         let mut event = storage::get_event(&env, event_id)?;
@@ -202,6 +387,62 @@ impl LumentixContract {
         Ok(())
     }
 
+    /// Publish a Draft event with an M-of-N signer set guarding its escrow.
+    /// The organizer or the admin can publish; once attached, `threshold` signer
+    /// approvals are required for `release_escrow` and `withdraw_funds`.
+    pub fn publish_event(
+        env: Env,
+        caller: Address,
+        event_id: u64,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        caller.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+
+        // Only organizer or admin can publish with signers
+        if event.organizer != caller && storage::get_admin(&env) != caller {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if event.status != EventStatus::Draft {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        if threshold == 0 || threshold > signers.len() {
+            return Err(LumentixError::InvalidThreshold);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                return Err(LumentixError::InvalidAddress);
+            }
+        }
+
+        storage::set_escrow_signers(
+            &env,
+            event_id,
+            &EscrowSigners {
+                signers: signers.clone(),
+                threshold,
+            },
+        );
+
+        let old_status = event.status.clone();
+        event.status = EventStatus::Published;
+        storage::set_event(&env, event_id, &event);
+
+        // Emit EscrowSignersSet event
+        EscrowSignersSet::emit(&env, event_id, caller.clone(), signers, threshold);
+
+        // Emit EventStatusChanged event
+        EventStatusChanged::emit(&env, event_id, caller, old_status, EventStatus::Published);
+
+        Ok(())
+    }
+
     /// Purchase a ticket for a published event.
     /// Checks capacity: rejects with EventSoldOut when tickets_sold >= max_tickets.
     /// Increments tickets_sold on success.
@@ -211,68 +452,146 @@ impl LumentixContract {
         event_id: u64,
         amount: i128,
     ) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         buyer.require_auth();
 
-        let mut event = storage::get_event(&env, event_id)?;
+        Self::sell_ticket(&env, buyer, event_id, amount)
+    }
 
-        // Event must be published
-        if event.status != EventStatus::Published {
-            return Err(LumentixError::InvalidStatusTransition);
+    /// Purchase a gift ticket claimable by whoever presents the preimage of `code_hash`
+    /// through `commit_gift_claim` followed by `claim_gift`.
+    /// The contract holds the ticket until it is claimed with `claim_gift`. After
+    /// `expires_at`, or if the event is cancelled, the purchaser can take it back
+    /// with `reclaim_gift` (and then refund it as usual for cancelled events).
+    pub fn purchase_gift_ticket(
+        env: Env,
+        purchaser: Address,
+        event_id: u64,
+        amount: i128,
+        code_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        purchaser.require_auth();
+
+        if expires_at <= env.ledger().timestamp() {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        if storage::has_gift(&env, &code_hash) {
+            return Err(LumentixError::GiftCodeExists);
         }
+        // Claiming is a transfer, so only events whose policy allows one can be gifted
+        let event = storage::get_event(&env, event_id)?;
+        transfer::check_policy(&env, &event, false)?;
 
-        // Check capacity — reject when sold out
-        if event.tickets_sold >= event.max_tickets {
-            return Err(LumentixError::EventSoldOut);
+        let ticket_id = Self::sell_ticket(&env, env.current_contract_address(), event_id, amount)?;
+
+        storage::set_gift(
+            &env,
+            &Gift {
+                ticket_id,
+                purchaser: purchaser.clone(),
+                code_hash,
+                expires_at,
+            },
+        );
+
+        // Emit GiftPurchased event
+        GiftPurchased::emit(&env, ticket_id, event_id, purchaser, expires_at);
+
+        Ok(ticket_id)
+    }
+
+    /// Commit to claiming a gift without revealing its secret. `commitment` is
+    /// sha256(secret ‖ claimer XDR). The secret can only be revealed with `claim_gift`
+    /// in a later ledger, so a claim seen in the mempool cannot be front-run.
+    pub fn commit_gift_claim(
+        env: Env,
+        claimer: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        claimer.require_auth();
+
+        storage::set_gift_claim(&env, &commitment, env.ledger().sequence());
+
+        Ok(())
+    }
+
+    /// Claim a gift ticket into `claimer` by presenting the secret whose sha256 was
+    /// registered at purchase. The claimer must have committed to the claim with
+    /// `commit_gift_claim` in an earlier ledger, no more than `GIFT_CLAIM_WINDOW` ledgers
+    /// ago. Gifts cannot be claimed after they expire, and claiming is subject to the
+    /// event's transfer policy like any other transfer.
+    pub fn claim_gift(env: Env, claimer: Address, secret: Bytes) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        claimer.require_auth();
+
+        let code_hash: BytesN<32> = env.crypto().sha256(&secret).into();
+        let gift = storage::get_gift(&env, &code_hash)?;
+        if env.ledger().timestamp() > gift.expires_at {
+            return Err(LumentixError::GiftExpired);
         }
 
-        // Validate payment amount
-        if amount < event.ticket_price {
-            return Err(LumentixError::InsufficientFunds);
+        let commitment = Self::gift_claim_commitment(&env, &secret, &claimer);
+        match storage::get_gift_claim(&env, &commitment) {
+            Some(ledger) if ledger < env.ledger().sequence() => {}
+            _ => return Err(LumentixError::GiftClaimNotCommitted),
         }
 
-        // Calculate platform fee
-        let fee_bps = storage::get_platform_fee_bps(&env);
-        let platform_fee = (amount * fee_bps as i128) / 10000;
-        let escrow_amount = amount - platform_fee;
+        let mut ticket = storage::get_ticket(&env, gift.ticket_id)?;
+        let event = transfer::check_transferable(&env, &ticket)?;
+        transfer::check_policy(&env, &event, false)?;
 
-        // Collect platform fee
-        if platform_fee > 0 {
-            storage::add_platform_balance(&env, platform_fee);
-        }
+        transfer::reassign(&env, &mut ticket, &claimer);
+        storage::remove_gift(&env, &code_hash);
+        storage::remove_gift_claim(&env, &commitment);
 
-        // Add to escrow
-        storage::add_escrow(&env, event_id, escrow_amount);
+        // Emit GiftClaimed event
+        GiftClaimed::emit(&env, ticket.id, ticket.event_id, claimer);
 
-        // Increment tickets_sold counter
-        event.tickets_sold += 1;
-        storage::set_event(&env, event_id, &event);
+        Ok(ticket.id)
+    }
 
-        // Create ticket
-        let ticket_id = storage::get_next_ticket_id(&env);
-        storage::increment_ticket_id(&env);
+    /// Take back an unclaimed gift ticket once it has expired or its event was cancelled.
+    /// Returning the ticket to its purchaser is not a transfer, so the event's transfer
+    /// policy does not apply. Only the purchaser can reclaim.
+    pub fn reclaim_gift(
+        env: Env,
+        purchaser: Address,
+        code_hash: BytesN<32>,
+    ) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
 
-        let ticket = Ticket {
-            id: ticket_id,
-            event_id,
-            owner: buyer,
-            purchase_time: env.ledger().timestamp(),
-            used: false,
-            refunded: false,
-        };
+        purchaser.require_auth();
 
-        storage::set_ticket(&env, ticket_id, &ticket);
+        let gift = storage::get_gift(&env, &code_hash)?;
+        if gift.purchaser != purchaser {
+            return Err(LumentixError::Unauthorized);
+        }
 
-        TicketPurchased::emit(
-            &env,
-            ticket_id,
-            event_id,
-            ticket.owner,
-            amount,
-            platform_fee,
-            escrow_amount,
-        );
+        let mut ticket = storage::get_ticket(&env, gift.ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if env.ledger().timestamp() <= gift.expires_at && event.status != EventStatus::Cancelled {
+            return Err(LumentixError::GiftNotExpired);
+        }
 
-        Ok(ticket_id)
+        transfer::reassign(&env, &mut ticket, &purchaser);
+        storage::remove_gift(&env, &code_hash);
+
+        // Emit GiftReclaimed event
+        GiftReclaimed::emit(&env, ticket.id, ticket.event_id, purchaser);
+
+        Ok(ticket.id)
+    }
+
+    /// Get an unclaimed gift by the hash of its claim code.
+    pub fn get_gift(env: Env, code_hash: BytesN<32>) -> Result<Gift, LumentixError> {
+        storage::get_gift(&env, &code_hash)
     }
 
     /// Purchase multiple tickets in a single transaction for a published event.
@@ -285,6 +604,8 @@ impl LumentixContract {
         quantity: u32,
         total_amount: i128,
     ) -> Result<Vec<u64>, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         buyer.require_auth();
 
         // Validate quantity is positive and within batch limit
@@ -304,9 +625,7 @@ impl LumentixContract {
 
         // Validate total_amount matches expected price
         let expected_amount = event.ticket_price * quantity as i128;
-        if total_amount != expected_amount {
-            return Err(LumentixError::InsufficientFunds);
-        }
+        fees::validate_exact_payment(total_amount, expected_amount)?;
 
         // Check availability for the requested quantity
         let available = event.max_tickets.saturating_sub(event.tickets_sold);
@@ -316,8 +635,10 @@ impl LumentixContract {
 
         // Calculate platform fee for total amount
         let fee_bps = storage::get_platform_fee_bps(&env);
-        let platform_fee = (total_amount * fee_bps as i128) / 10000;
-        let escrow_amount = total_amount - platform_fee;
+        let fees::FeeSplit {
+            platform_fee,
+            escrow_amount,
+        } = fees::split_payment(total_amount, fee_bps);
 
         // Collect platform fee
         if platform_fee > 0 {
@@ -335,10 +656,13 @@ impl LumentixContract {
         let mut ticket_ids = Vec::new(&env);
         let purchase_time = env.ledger().timestamp();
 
-        for _ in 0..quantity {
+        for index in 0..quantity {
             let ticket_id = storage::get_next_ticket_id(&env);
             storage::increment_ticket_id(&env);
 
+            // Each ticket records its exact share of the batch totals
+            let split = fees::split_batch_ticket(event.ticket_price, quantity, fee_bps, index);
+
             let ticket = Ticket {
                 id: ticket_id,
                 event_id,
@@ -346,102 +670,1222 @@ impl LumentixContract {
                 purchase_time,
                 used: false,
                 refunded: false,
+                paid_amount: event.ticket_price,
+                platform_fee: split.platform_fee,
+                escrow_amount: split.escrow_amount,
+                sessions: Vec::new(&env),
             };
 
             storage::set_ticket(&env, ticket_id, &ticket);
+            storage::add_event_ticket(&env, ticket.event_id, ticket_id);
+            storage::increment_balance(&env, &buyer);
             ticket_ids.push_back(ticket_id);
 
-            // Emit event for each ticket
             TicketPurchased::emit(
                 &env,
                 ticket_id,
                 event_id,
                 buyer.clone(),
                 event.ticket_price,
-                platform_fee / quantity as i128,
-                escrow_amount / quantity as i128,
+                split.platform_fee,
+                split.escrow_amount,
             );
         }
 
         Ok(ticket_ids)
     }
 
-    /// Mark a ticket as used (check-in at event).
-    /// Only the event organizer can use tickets.
-    pub fn use_ticket(env: Env, ticket_id: u64, caller: Address) -> Result<(), LumentixError> {
-        caller.require_auth();
+    /// Create a bundle (e.g. a season pass) linking several of the organizer's events
+    /// under one price and capacity. `weights` sets how each purchase is split across
+    /// the events' escrows and must have one positive entry per event.
+    pub fn create_bundle(
+        env: Env,
+        organizer: Address,
+        name: String,
+        event_ids: Vec<u64>,
+        weights: Vec<u32>,
+        price: i128,
+        max_passes: u32,
+    ) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
 
-        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        organizer.require_auth();
 
-        if ticket.used {
-            return Err(LumentixError::TicketAlreadyUsed);
+        validation::validate_string_not_empty(&name)?;
+        validation::validate_positive_amount(price)?;
+        validation::validate_positive_capacity(max_passes)?;
+
+        if event_ids.is_empty()
+            || event_ids.len() > MAX_BUNDLE_EVENTS
+            || event_ids.len() != weights.len()
+        {
+            return Err(LumentixError::InvalidBundle);
         }
 
-        // Only organizer can validate tickets
-        let event = storage::get_event(&env, ticket.event_id)?;
-        if event.organizer != caller {
-            return Err(LumentixError::Unauthorized);
+        for (i, event_id) in event_ids.iter().enumerate() {
+            if weights.get(i as u32).unwrap_or(0) == 0 {
+                return Err(LumentixError::InvalidBundle);
+            }
+
+            // Each event may appear only once
+            if event_ids.first_index_of(event_id) != Some(i as u32) {
+                return Err(LumentixError::InvalidBundle);
+            }
+
+            let event = storage::get_event(&env, event_id)?;
+            if event.organizer != organizer {
+                return Err(LumentixError::Unauthorized);
+            }
+            if event.status == EventStatus::Cancelled || event.status == EventStatus::Completed {
+                return Err(LumentixError::InvalidStatusTransition);
+            }
         }
 
-        ticket.used = true;
-        storage::set_ticket(&env, ticket_id, &ticket);
+        let bundle_id = storage::get_next_bundle_id(&env);
+        storage::increment_bundle_id(&env);
 
-        // Emit TicketUsed event
-        TicketUsed::emit(&env, ticket_id, ticket.event_id, ticket.owner, caller);
+        let bundle = Bundle {
+            id: bundle_id,
+            organizer: organizer.clone(),
+            name,
+            event_ids,
+            weights,
+            price,
+            max_passes,
+            passes_sold: 0,
+        };
 
-        Ok(())
+        storage::set_bundle(&env, bundle_id, &bundle);
+        for event_id in bundle.event_ids.iter() {
+            storage::add_bundle_ref(&env, event_id);
+        }
+
+        BundleCreated::emit(
+            &env,
+            bundle_id,
+            organizer,
+            bundle.event_ids,
+            bundle.price,
+            bundle.max_passes,
+        );
+
+        Ok(bundle_id)
     }
 
-    /// Transfer a ticket from one owner to another.
-    /// Only the current ticket owner can transfer it.
-    /// Tickets can only be transferred for published events.
-    /// Used or refunded tickets cannot be transferred.
-    pub fn transfer_ticket(
+    /// Purchase a bundle. Mints one ticket per linked event, each recording its
+    /// weighted share of the platform fee and escrow, so cancelling one event
+    /// refunds only that event's share. Every linked event must be published
+    /// and have a seat available.
+    pub fn purchase_bundle(
         env: Env,
-        ticket_id: u64,
-        from: Address,
-        to: Address,
-    ) -> Result<(), LumentixError> {
-        from.require_auth();
+        buyer: Address,
+        bundle_id: u64,
+        amount: i128,
+    ) -> Result<Vec<u64>, LumentixError> {
+        migration::ensure_migrated(&env)?;
 
-        // Read the ticket
-        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        buyer.require_auth();
 
-        // Verify the caller is the current owner
-        if ticket.owner != from {
-            return Err(LumentixError::Unauthorized);
-        }
+        let mut bundle = storage::get_bundle(&env, bundle_id)?;
 
-        // Verify ticket is not used
-        if ticket.used {
-            return Err(LumentixError::TicketAlreadyUsed);
+        if bundle.passes_sold >= bundle.max_passes {
+            return Err(LumentixError::EventSoldOut);
         }
 
-        // Verify ticket is not refunded
-        if ticket.refunded {
-            return Err(LumentixError::RefundNotAllowed);
-        }
+        fees::validate_exact_payment(amount, bundle.price)?;
 
-        // Read the event and verify it's published
+        // Check every event before mutating anything
+        let mut events = Vec::new(&env);
+        for event_id in bundle.event_ids.iter() {
+            let event = storage::get_event(&env, event_id)?;
+            if event.status != EventStatus::Published {
+                return Err(LumentixError::InvalidStatusTransition);
+            }
+            if event.tickets_sold >= event.max_tickets {
+                return Err(LumentixError::EventSoldOut);
+            }
+            events.push_back(event);
+        }
+
+        let fee_bps = storage::get_platform_fee_bps(&env);
+        let total = fees::split_payment(amount, fee_bps);
+
+        if total.platform_fee > 0 {
+            storage::add_platform_balance(&env, total.platform_fee);
+        }
+
+        let mut ticket_ids = Vec::new(&env);
+        let purchase_time = env.ledger().timestamp();
+
+        for (i, mut event) in events.iter().enumerate() {
+            let index = i as u32;
+            let platform_fee = fees::weighted_share(total.platform_fee, &bundle.weights, index);
+            let escrow_amount = fees::weighted_share(total.escrow_amount, &bundle.weights, index);
+
+            storage::add_escrow(&env, event.id, escrow_amount);
+
+            event.tickets_sold += 1;
+            storage::set_event(&env, event.id, &event);
+
+            let ticket_id = storage::get_next_ticket_id(&env);
+            storage::increment_ticket_id(&env);
+
+            let ticket = Ticket {
+                id: ticket_id,
+                event_id: event.id,
+                owner: buyer.clone(),
+                purchase_time,
+                used: false,
+                refunded: false,
+                paid_amount: platform_fee + escrow_amount,
+                platform_fee,
+                escrow_amount,
+                sessions: Vec::new(&env),
+            };
+
+            storage::set_ticket(&env, ticket_id, &ticket);
+            storage::add_event_ticket(&env, ticket.event_id, ticket_id);
+            storage::increment_balance(&env, &buyer);
+            ticket_ids.push_back(ticket_id);
+
+            TicketPurchased::emit(
+                &env,
+                ticket_id,
+                event.id,
+                buyer.clone(),
+                ticket.paid_amount,
+                platform_fee,
+                escrow_amount,
+            );
+        }
+
+        bundle.passes_sold += 1;
+        storage::set_bundle(&env, bundle_id, &bundle);
+
+        BundlePurchased::emit(
+            &env,
+            bundle_id,
+            buyer,
+            ticket_ids.clone(),
+            amount,
+            total.platform_fee,
+        );
+
+        Ok(ticket_ids)
+    }
+
+    /// Get bundle data by ID.
+    pub fn get_bundle(env: Env, bundle_id: u64) -> Result<Bundle, LumentixError> {
+        storage::get_bundle(&env, bundle_id)
+    }
+
+    /// Create a recurring event series from a template and a recurrence rule.
+    /// Occurrences are created as Draft events with `instantiate_series`.
+    /// Occurrences may not overlap: the interval must be at least the duration. The first
+    /// occurrence must start in the future and the last must end within the u64 time range.
+    pub fn create_series(
+        env: Env,
+        organizer: Address,
+        template: SeriesTemplate,
+        recurrence: Recurrence,
+    ) -> Result<u64, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        validation::validate_string_not_empty(&template.name)?;
+        validation::validate_string_not_empty(&template.description)?;
+        validation::validate_string_not_empty(&template.location)?;
+        validation::validate_positive_amount(template.ticket_price)?;
+        validation::validate_positive_capacity(template.max_tickets)?;
+
+        if recurrence.start_time <= env.ledger().timestamp() {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        if template.duration == 0
+            || recurrence.interval == 0
+            || recurrence.interval < template.duration
+        {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        if recurrence.count == 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        if recurrence.count > MAX_SERIES_OCCURRENCES {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        Self::occurrence_times(&recurrence, template.duration, recurrence.count - 1)?;
+
+        let series_id = storage::get_next_series_id(&env);
+        storage::increment_series_id(&env);
+
+        let series = EventSeries {
+            id: series_id,
+            organizer: organizer.clone(),
+            template,
+            recurrence,
+            event_ids: Vec::new(&env),
+        };
+
+        storage::set_series(&env, series_id, &series);
+
+        // Emit SeriesCreated event
+        SeriesCreated::emit(
+            &env,
+            series_id,
+            organizer,
+            series.recurrence.interval,
+            series.recurrence.count,
+        );
+
+        Ok(series_id)
+    }
+
+    /// Instantiate the next `count` occurrences of a series as Draft events linked to it.
+    /// Stops at the end of the recurrence; fails with SeriesExhausted if none remain.
+    /// Only the series organizer can instantiate. Returns the new event ids.
+    pub fn instantiate_series(
+        env: Env,
+        organizer: Address,
+        series_id: u64,
+        count: u32,
+    ) -> Result<Vec<u64>, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut series = storage::get_series(&env, series_id)?;
+        if series.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let instantiated = series.event_ids.len();
+        let remaining = series.recurrence.count - instantiated;
+        if remaining == 0 {
+            return Err(LumentixError::SeriesExhausted);
+        }
+        if count == 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        let template = series.template.clone();
+        let mut new_event_ids = Vec::new(&env);
+
+        for occurrence in instantiated..instantiated + count.min(remaining) {
+            let (start_time, end_time) =
+                Self::occurrence_times(&series.recurrence, template.duration, occurrence)?;
+
+            let event_id = storage::get_next_event_id(&env);
+            storage::increment_event_id(&env);
+
+            let event = Event {
+                id: event_id,
+                organizer: organizer.clone(),
+                name: template.name.clone(),
+                description: template.description.clone(),
+                location: template.location.clone(),
+                start_time,
+                end_time,
+                ticket_price: template.ticket_price,
+                max_tickets: template.max_tickets,
+                tickets_sold: 0,
+                status: EventStatus::Draft,
+                reentry_allowed: false,
+                checkin_window: DEFAULT_CHECKIN_WINDOW,
+                badges_enabled: false,
+                transfer_policy: TransferPolicy::Free,
+                metadata_uri: None,
+                content_hash: None,
+                category: EventCategory::Other,
+                tags: Vec::new(&env),
+                series_id,
+            };
+
+            storage::set_event(&env, event_id, &event);
+            storage::index_event(&env, &event);
+
+            // Emit EventCreated event
+            EventCreated::emit(
+                &env,
+                event_id,
+                organizer.clone(),
+                event.name,
+                event.ticket_price,
+                event.max_tickets,
+                start_time,
+                end_time,
+            );
+
+            series.event_ids.push_back(event_id);
+            new_event_ids.push_back(event_id);
+        }
+
+        storage::set_series(&env, series_id, &series);
+
+        Ok(new_event_ids)
+    }
+
+    /// Get event series data by ID.
+    pub fn get_series(env: Env, series_id: u64) -> Result<EventSeries, LumentixError> {
+        storage::get_series(&env, series_id)
+    }
+
+    /// Get the instantiated occurrences of a series, in order.
+    pub fn get_series_occurrences(env: Env, series_id: u64) -> Result<Vec<Event>, LumentixError> {
+        let series = storage::get_series(&env, series_id)?;
+
+        let mut events = Vec::new(&env);
+        for event_id in series.event_ids.iter() {
+            if let Ok(event) = storage::get_event(&env, event_id) {
+                events.push_back(event);
+            }
+        }
+        Ok(events)
+    }
+
+    /// Mark a ticket as used (check-in at event).
+    /// Only the event organizer or one of its validators can use tickets.
+    /// For events with sessions, checks the ticket in to the session running now;
+    /// the ticket is only marked used once every session it grants has been attended.
+    pub fn use_ticket(env: Env, ticket_id: u64, caller: Address) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        caller.require_auth();
+
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+
+        // Only organizer or validators can validate tickets
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if !admission::is_gate_agent(&env, &event, &caller) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let session_id = admission::admit(&env, &mut ticket, &event, env.ledger().timestamp())?;
+        storage::set_ticket(&env, ticket_id, &ticket);
+
+        if session_id != NO_SESSION {
+            SessionCheckedIn::emit(&env, ticket_id, ticket.event_id, session_id);
+        }
+
+        // Emit TicketUsed event
+        TicketUsed::emit(&env, ticket_id, ticket.event_id, ticket.owner, caller);
+
+        Ok(())
+    }
+
+    /// Check in a batch of tickets for an event in one transaction.
+    /// Only the event organizer or one of its validators can submit a batch.
+    /// Valid tickets are admitted; invalid ones are skipped rather than failing the
+    /// batch. Returns one `CheckInResult` per ticket id, in order.
+    pub fn batch_check_in(
+        env: Env,
+        caller: Address,
+        event_id: u64,
+        ticket_ids: Vec<u64>,
+    ) -> Result<Vec<CheckInResult>, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        caller.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if !admission::is_gate_agent(&env, &event, &caller) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut results = Vec::new(&env);
+
+        for ticket_id in ticket_ids.iter() {
+            let mut ticket = match storage::get_ticket(&env, ticket_id) {
+                Ok(ticket) => ticket,
+                Err(_) => {
+                    results.push_back(CheckInResult::NotFound);
+                    continue;
+                }
+            };
+            if ticket.event_id != event_id {
+                results.push_back(CheckInResult::WrongEvent);
+                continue;
+            }
+
+            let session_id = match admission::admit(&env, &mut ticket, &event, now) {
+                Ok(session_id) => session_id,
+                Err(err) => {
+                    results.push_back(admission::check_in_result(err));
+                    continue;
+                }
+            };
+            storage::set_ticket(&env, ticket_id, &ticket);
+
+            if session_id != NO_SESSION {
+                SessionCheckedIn::emit(&env, ticket_id, event_id, session_id);
+            }
+            TicketUsed::emit(&env, ticket_id, event_id, ticket.owner, caller.clone());
+
+            results.push_back(CheckInResult::Ok);
+        }
+
+        Ok(results)
+    }
+
+    /// Authorize a validator (gate agent) to check tickets in and out for an event.
+    /// Only the event organizer can add validators.
+    pub fn add_validator(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_validator(&env, event_id, &validator, true);
+
+        // Emit ValidatorAdded event
+        ValidatorAdded::emit(&env, event_id, validator);
+
+        Ok(())
+    }
+
+    /// Remove a validator (gate agent) from an event.
+    /// Only the event organizer can remove validators.
+    pub fn remove_validator(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_validator(&env, event_id, &validator, false);
+
+        // Emit ValidatorRemoved event
+        ValidatorRemoved::emit(&env, event_id, validator);
+
+        Ok(())
+    }
+
+    /// Check whether an address is an authorized validator for an event.
+    pub fn is_validator(env: Env, event_id: u64, validator: Address) -> bool {
+        storage::is_validator(&env, event_id, &validator)
+    }
+
+    /// Register the ed25519 public key used to sign offline entry passes for a ticket.
+    /// Only the ticket owner can register a key; transferring the ticket clears it.
+    pub fn register_pass_key(
+        env: Env,
+        holder: Address,
+        ticket_id: u64,
+        public_key: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        holder.require_auth();
+
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != holder {
+            return Err(LumentixError::Unauthorized);
+        }
+        if ticket.used {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
+        if ticket.refunded {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+
+        storage::set_pass_key(&env, ticket_id, &public_key);
+
+        Ok(())
+    }
+
+    /// Get the entry pass public key registered for a ticket, if any.
+    pub fn get_pass_key(env: Env, ticket_id: u64) -> Result<Option<BytesN<32>>, LumentixError> {
+        let _ = storage::get_ticket(&env, ticket_id)?;
+        Ok(storage::get_pass_key(&env, ticket_id))
+    }
+
+    /// Submit entry pass scans collected offline at the gate.
+    ///
+    /// Each scan must carry a signature from the ticket's registered pass key over
+    /// [`admission::pass_message`], be newer than the ticket's last accepted scan,
+    /// and is admitted as if checked in at its signed timestamp. Only the organizer
+    /// or a validator of each scan's event can submit it. Invalid scans are skipped
+    /// rather than failing the upload; an invalid signature still aborts it, as the
+    /// host traps on failed verification. Returns one `CheckInResult` per scan, in order.
+    pub fn submit_signed_scans(
+        env: Env,
+        caller: Address,
+        scans: Vec<SignedScan>,
+    ) -> Result<Vec<CheckInResult>, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        caller.require_auth();
+
+        let mut results = Vec::new(&env);
+        for scan in scans.iter() {
+            results.push_back(match Self::admit_signed_scan(&env, &caller, &scan) {
+                Ok(()) => CheckInResult::Ok,
+                Err(err) => admission::check_in_result(err),
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Scan a checked-in ticket out of the venue so it can re-enter later.
+    /// Only the event organizer or its validators can scan out, and the event must allow re-entry.
+    pub fn scan_out(env: Env, ticket_id: u64, caller: Address) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        caller.require_auth();
+
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if !admission::is_gate_agent(&env, &event, &caller) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let session_id = admission::scan_out(&env, &ticket, &event)?;
+
+        // Emit TicketScannedOut event
+        TicketScannedOut::emit(&env, ticket_id, ticket.event_id, session_id, caller);
+
+        Ok(())
+    }
+
+    /// Enable or disable re-entry (scan-out / scan-in pairs) for an event.
+    /// Only the event organizer can change the re-entry mode.
+    pub fn set_reentry_mode(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        allowed: bool,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        event.reentry_allowed = allowed;
+        storage::set_event(&env, event_id, &event);
+
+        Ok(())
+    }
+
+    /// Set the discovery category of an event. Events default to `EventCategory::Other`.
+    /// Only the organizer can change it, and not after the event has ended or been cancelled.
+    pub fn set_event_category(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        category: EventCategory,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status == EventStatus::Cancelled || event.status == EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        storage::remove_from_index(&env, &EventIndex::Category(event.category), event_id);
+        storage::add_to_index(&env, &EventIndex::Category(category), event_id);
+
+        event.category = category;
+        storage::set_event(&env, event_id, &event);
+
+        Ok(())
+    }
+
+    /// Replace the discovery tags of an event (at most MAX_EVENT_TAGS, no duplicates).
+    /// Only the organizer can change them, and not after the event has ended or been cancelled.
+    pub fn set_event_tags(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        tags: Vec<Symbol>,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status == EventStatus::Cancelled || event.status == EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        if tags.len() > MAX_EVENT_TAGS {
+            return Err(LumentixError::InvalidTags);
+        }
+        for (i, tag) in tags.iter().enumerate() {
+            if tags.first_index_of(&tag) != Some(i as u32) {
+                return Err(LumentixError::InvalidTags);
+            }
+        }
+
+        for tag in event.tags.iter() {
+            storage::remove_from_index(&env, &EventIndex::Tag(tag), event_id);
+        }
+        for tag in tags.iter() {
+            storage::add_to_index(&env, &EventIndex::Tag(tag), event_id);
+        }
+
+        event.tags = tags;
+        storage::set_event(&env, event_id, &event);
+
+        Ok(())
+    }
+
+    /// Set the transfer policy of an event. Events default to `TransferPolicy::Free`.
+    /// Only the event organizer can change the policy; it applies to every transfer path.
+    pub fn set_transfer_policy(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        policy: TransferPolicy,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        event.transfer_policy = policy;
+        storage::set_event(&env, event_id, &event);
+
+        Ok(())
+    }
+
+    /// Opt an event in to (or out of) proof-of-attendance badges minted at check-in.
+    /// Only the event organizer can change the badge mode.
+    pub fn set_badge_mode(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        enabled: bool,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        event.badges_enabled = enabled;
+        storage::set_event(&env, event_id, &event);
+
+        Ok(())
+    }
+
+    /// Get a page of the attendance badges held by an address, in minting order.
+    /// Returns up to `limit` badges (at most `MAX_PAGE_SIZE`) starting at index `start`.
+    pub fn get_badges(env: Env, holder: Address, start: u32, limit: u32) -> Vec<AttendanceBadge> {
+        storage::get_badges(&env, &holder, start, limit.min(MAX_PAGE_SIZE))
+    }
+
+    /// Get the number of attendance badges held by an address.
+    pub fn get_badge_count(env: Env, holder: Address) -> u32 {
+        storage::get_holder_badge_count(&env, &holder)
+    }
+
+    /// Get a page of the addresses holding an event's attendance badge, in minting order.
+    /// Returns up to `limit` holders (at most `MAX_PAGE_SIZE`) starting at index `start`.
+    pub fn get_badge_holders(
+        env: Env,
+        event_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, LumentixError> {
+        let _ = storage::get_event(&env, event_id)?;
+        Ok(storage::get_badge_holders(
+            &env,
+            event_id,
+            start,
+            limit.min(MAX_PAGE_SIZE),
+        ))
+    }
+
+    /// Get the number of holders of an event's attendance badge.
+    pub fn get_badge_holder_count(env: Env, event_id: u64) -> Result<u32, LumentixError> {
+        let _ = storage::get_event(&env, event_id)?;
+        Ok(storage::get_badge_holder_count(&env, event_id))
+    }
+
+    /// Set the check-in window of an event. Events default to `DEFAULT_CHECKIN_WINDOW`.
+    /// Only the event organizer can configure the window.
+    pub fn set_checkin_window(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        window: CheckInWindow,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        event.checkin_window = window;
+        storage::set_event(&env, event_id, &event);
+
+        Ok(())
+    }
+
+    /// Grant or revoke early entry for a ticket (e.g. VIP holders), letting it
+    /// check in before the event's check-in window opens.
+    /// Only the event organizer can grant early entry.
+    pub fn set_early_entry(
+        env: Env,
+        organizer: Address,
+        ticket_id: u64,
+        allowed: bool,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_early_entry(&env, ticket_id, allowed);
+
+        Ok(())
+    }
+
+    /// Add an admission session (e.g. one festival day) to an event.
+    /// Sessions must fall within the event's time range and must not overlap.
+    /// Only the organizer can add sessions, and not once tickets are sold or after the
+    /// event has ended or been cancelled.
+    pub fn add_session(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<u32, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status == EventStatus::Cancelled || event.status == EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        // Sold tickets were admitted under the existing sessions
+        if event.tickets_sold > 0 {
+            return Err(LumentixError::SessionsLocked);
+        }
+
+        validation::validate_time_range(start_time, end_time)?;
+        if start_time < event.start_time || end_time > event.end_time {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+
+        let mut sessions = storage::get_sessions(&env, event_id);
+        if sessions.len() >= MAX_EVENT_SESSIONS {
+            return Err(LumentixError::CapacityExceeded);
+        }
+        for session in sessions.iter() {
+            if start_time <= session.end_time && session.start_time <= end_time {
+                return Err(LumentixError::InvalidTimeRange);
+            }
+        }
+
+        let session_id = sessions.len() + 1;
+        sessions.push_back(Session {
+            id: session_id,
+            start_time,
+            end_time,
+        });
+        storage::set_sessions(&env, event_id, &sessions);
+
+        // Emit SessionAdded event
+        SessionAdded::emit(&env, event_id, session_id, start_time, end_time);
+
+        Ok(session_id)
+    }
+
+    /// Get the admission sessions of an event.
+    /// Returns an empty vector if the event has no sessions.
+    pub fn get_event_sessions(env: Env, event_id: u64) -> Result<Vec<Session>, LumentixError> {
+        let _ = storage::get_event(&env, event_id)?;
+        Ok(storage::get_sessions(&env, event_id))
+    }
+
+    /// Restrict which sessions a ticket admits to. An empty list grants every session.
+    /// Only the event organizer can change a ticket's sessions, and not once it checked in.
+    pub fn set_ticket_sessions(
+        env: Env,
+        organizer: Address,
+        ticket_id: u64,
+        sessions: Vec<u32>,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
         let event = storage::get_event(&env, ticket.event_id)?;
-        if event.status != EventStatus::Published {
-            return Err(LumentixError::InvalidStatusTransition);
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        // Granted sessions decide when a ticket becomes used, so they are fixed once it checked in
+        if admission::has_checked_in(&env, &ticket) {
+            return Err(LumentixError::SessionsLocked);
+        }
+
+        let event_sessions = storage::get_sessions(&env, ticket.event_id);
+        for session_id in sessions.iter() {
+            if session_id == NO_SESSION || session_id > event_sessions.len() {
+                return Err(LumentixError::SessionNotFound);
+            }
         }
 
-        // Update ticket owner
-        ticket.owner = to.clone();
+        ticket.sessions = sessions;
         storage::set_ticket(&env, ticket_id, &ticket);
 
-        // Emit TicketTransferred event
-        TicketTransferred::emit(&env, ticket_id, ticket.event_id, from, to);
+        Ok(())
+    }
+
+    /// Get the addresses of attendees checked in to a specific session of an event.
+    /// Deduplicates so each address appears once.
+    pub fn get_session_attendees(
+        env: Env,
+        event_id: u64,
+        session_id: u32,
+    ) -> Result<Vec<Address>, LumentixError> {
+        let _ = storage::get_event(&env, event_id)?;
+
+        let sessions = storage::get_sessions(&env, event_id);
+        if session_id == NO_SESSION || session_id > sessions.len() {
+            return Err(LumentixError::SessionNotFound);
+        }
+
+        let mut attendees: Vec<Address> = Vec::new(&env);
+        for ticket_id in storage::get_event_ticket_ids(&env, event_id).iter() {
+            if let Ok(ticket) = storage::get_ticket(&env, ticket_id) {
+                if storage::get_admission(&env, ticket_id, session_id).is_some()
+                    && !attendees.contains(&ticket.owner)
+                {
+                    attendees.push_back(ticket.owner);
+                }
+            }
+        }
+
+        Ok(attendees)
+    }
+
+    /// Transfer a ticket from one owner to another.
+    /// Only the current ticket owner can transfer it.
+    /// Tickets can only be transferred for published events, within the event's transfer policy.
+    /// Used or refunded tickets cannot be transferred.
+    pub fn transfer_ticket(
+        env: Env,
+        ticket_id: u64,
+        from: Address,
+        to: Address,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        from.require_auth();
+
+        // Read the ticket
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+
+        // Verify the caller is the current owner
+        if ticket.owner != from {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        transfer::transfer(&env, &mut ticket, &to, false)
+    }
+
+    /// Request an organizer-approved transfer of a ticket to `to`.
+    /// Only the ticket owner can request, one pending request per ticket.
+    /// Soulbound tickets cannot be requested for transfer.
+    pub fn request_transfer(
+        env: Env,
+        holder: Address,
+        ticket_id: u64,
+        to: Address,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        holder.require_auth();
+
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != holder {
+            return Err(LumentixError::Unauthorized);
+        }
+        if storage::has_transfer_request(&env, ticket_id) {
+            return Err(LumentixError::TransferRequestExists);
+        }
+
+        let event = transfer::check_transferable(&env, &ticket)?;
+        transfer::check_policy(&env, &event, true)?;
+
+        storage::set_transfer_request(
+            &env,
+            &TransferRequest {
+                ticket_id,
+                from: holder.clone(),
+                to: to.clone(),
+                requested_at: env.ledger().timestamp(),
+            },
+        );
+
+        // Emit TransferRequested event
+        TransferRequested::emit(&env, ticket_id, ticket.event_id, holder, to);
+
+        Ok(())
+    }
+
+    /// Approve a pending transfer request, executing the transfer.
+    /// Only the event organizer or one of its validators can approve.
+    pub fn approve_transfer_request(
+        env: Env,
+        approver: Address,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        approver.require_auth();
+
+        let request = storage::get_transfer_request(&env, ticket_id)?;
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if !admission::is_gate_agent(&env, &event, &approver) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        transfer::transfer(&env, &mut ticket, &request.to, true)?;
+
+        // Emit TransferRequestApproved event
+        TransferRequestApproved::emit(&env, ticket_id, ticket.event_id, approver);
+
+        Ok(())
+    }
+
+    /// Reject a pending transfer request.
+    /// Only the event organizer or one of its validators can reject.
+    pub fn reject_transfer_request(
+        env: Env,
+        approver: Address,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        approver.require_auth();
+
+        let _ = storage::get_transfer_request(&env, ticket_id)?;
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if !admission::is_gate_agent(&env, &event, &approver) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::remove_transfer_request(&env, ticket_id);
+
+        // Emit TransferRequestRejected event
+        TransferRequestRejected::emit(&env, ticket_id, ticket.event_id, approver);
+
+        Ok(())
+    }
+
+    /// Cancel a pending transfer request. Only the requesting holder can cancel.
+    pub fn cancel_transfer_request(
+        env: Env,
+        holder: Address,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        holder.require_auth();
+
+        let request = storage::get_transfer_request(&env, ticket_id)?;
+        if request.from != holder {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        storage::remove_transfer_request(&env, ticket_id);
+
+        // Emit TransferRequestCancelled event
+        TransferRequestCancelled::emit(&env, ticket_id, ticket.event_id, holder);
+
+        Ok(())
+    }
+
+    /// Get the pending transfer request of a ticket.
+    pub fn get_transfer_request(
+        env: Env,
+        ticket_id: u64,
+    ) -> Result<TransferRequest, LumentixError> {
+        storage::get_transfer_request(&env, ticket_id)
+    }
+
+    /// Get the owner of a ticket.
+    pub fn owner_of(env: Env, ticket_id: u64) -> Result<Address, LumentixError> {
+        Ok(storage::get_ticket(&env, ticket_id)?.owner)
+    }
+
+    /// Get the number of tickets owned by an address.
+    pub fn balance_of(env: Env, owner: Address) -> u32 {
+        storage::get_balance(&env, &owner)
+    }
+
+    /// Approve an address to transfer a single ticket, or clear the approval with `None`.
+    /// Callable by the ticket owner or an operator approved for all of the owner's tickets.
+    /// The approval is cleared whenever the ticket is transferred.
+    pub fn approve(
+        env: Env,
+        caller: Address,
+        ticket_id: u64,
+        approved: Option<Address>,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        caller.require_auth();
+
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != caller && !storage::is_operator(&env, &ticket.owner, &caller) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        match &approved {
+            Some(address) => storage::set_ticket_approval(&env, ticket_id, address),
+            None => storage::remove_ticket_approval(&env, ticket_id),
+        }
+
+        // Emit TicketApproved event
+        TicketApproved::emit(&env, ticket_id, ticket.owner, approved);
+
+        Ok(())
+    }
+
+    /// Get the address approved to transfer a single ticket, if any.
+    pub fn get_approved(env: Env, ticket_id: u64) -> Result<Option<Address>, LumentixError> {
+        let _ = storage::get_ticket(&env, ticket_id)?;
+        Ok(storage::get_ticket_approval(&env, ticket_id))
+    }
+
+    /// Approve or revoke an operator allowed to transfer all of the owner's tickets.
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        owner.require_auth();
+
+        storage::set_operator(&env, &owner, &operator, approved);
+
+        // Emit ApprovalForAll event
+        ApprovalForAll::emit(&env, owner, operator, approved);
 
         Ok(())
     }
 
+    /// Check whether an operator is approved for all of the owner's tickets.
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        storage::is_operator(&env, &owner, &operator)
+    }
+
+    /// Transfer a ticket on behalf of its owner.
+    /// `spender` must be the owner, the ticket's approved address, or an approved operator.
+    /// The same rules as `transfer_ticket` apply.
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        spender.require_auth();
+
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != from {
+            return Err(LumentixError::Unauthorized);
+        }
+        if !transfer::is_approved_or_owner(&env, &ticket, &spender) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        transfer::transfer(&env, &mut ticket, &to, false)
+    }
+
+    /// Get token-style metadata for a ticket, derived from its event.
+    pub fn ticket_metadata(env: Env, ticket_id: u64) -> Result<TicketMetadata, LumentixError> {
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        let event = storage::get_event(&env, ticket.event_id)?;
+
+        Ok(TicketMetadata {
+            ticket_id,
+            event_id: event.id,
+            name: event.name,
+            description: event.description,
+            location: event.location,
+            start_time: event.start_time,
+            end_time: event.end_time,
+            used: ticket.used,
+            refunded: ticket.refunded,
+        })
+    }
+
     /// Refund a ticket for a cancelled event.
+    /// Refunds the escrow share recorded at purchase, plus the platform fee
+    /// when the refund policy is FullAmount. A fee the platform no longer holds is
+    /// reported as `fee_shortfall` in the TicketRefunded event.
     /// Decrements tickets_sold to free up capacity.
     /// The ticket must not be used or already refunded.
     pub fn refund_ticket(env: Env, ticket_id: u64, buyer: Address) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         buyer.require_auth();
 
         let mut ticket = storage::get_ticket(&env, ticket_id)?;
@@ -451,8 +1895,8 @@ impl LumentixContract {
             return Err(LumentixError::Unauthorized);
         }
 
-        // Cannot refund used tickets
-        if ticket.used {
+        // Cannot refund tickets that attended the event or any of its sessions
+        if admission::has_checked_in(&env, &ticket) {
             return Err(LumentixError::TicketAlreadyUsed);
         }
 
@@ -468,25 +1912,48 @@ impl LumentixContract {
             return Err(LumentixError::EventNotCancelled);
         }
 
+        // Return what was actually paid, not the current ticket price. The fee share
+        // is only returned while the platform still holds it, so fee withdrawals by
+        // the admin never block refunding the escrow share; the part that could not
+        // be returned is reported in the refund event.
+        let mut refund_amount = ticket.escrow_amount;
+        let mut fee_shortfall = 0;
+        if storage::get_refund_policy(&env) == RefundPolicy::FullAmount {
+            let fee_refund = ticket.platform_fee.min(storage::get_platform_balance(&env));
+            storage::deduct_platform_balance(&env, fee_refund)?;
+            refund_amount += fee_refund;
+            fee_shortfall = ticket.platform_fee - fee_refund;
+        }
+
         // Deduct from escrow
-        storage::deduct_escrow(&env, ticket.event_id, event.ticket_price)?;
+        storage::deduct_escrow(&env, ticket.event_id, ticket.escrow_amount)?;
 
         // Mark ticket as refunded
         ticket.refunded = true;
         storage::set_ticket(&env, ticket_id, &ticket);
+        storage::decrement_balance(&env, &buyer);
 
         // Decrement tickets_sold to free up capacity
         event.tickets_sold = event.tickets_sold.saturating_sub(1);
         storage::set_event(&env, ticket.event_id, &event);
 
         // Emit TicketRefunded event
-        TicketRefunded::emit(&env, ticket_id, ticket.event_id, buyer, event.ticket_price);
+        TicketRefunded::emit(
+            &env,
+            ticket_id,
+            ticket.event_id,
+            buyer,
+            refund_amount,
+            fee_shortfall,
+        );
 
         Ok(())
     }
 
     /// Cancel a published event. Only the organizer can cancel.
     pub fn cancel_event(env: Env, organizer: Address, event_id: u64) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         organizer.require_auth();
 
         let mut event = storage::get_event(&env, event_id)?;
@@ -512,6 +1979,8 @@ impl LumentixContract {
         organizer: Address,
         event_id: u64,
     ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         organizer.require_auth();
 
         let mut event = storage::get_event(&env, event_id)?;
@@ -529,58 +1998,153 @@ impl LumentixContract {
             return Err(LumentixError::InvalidStatusTransition);
         }
 
-        event.status = EventStatus::Completed;
-        storage::set_event(&env, event_id, &event);
+        event.status = EventStatus::Completed;
+        storage::set_event(&env, event_id, &event);
+
+        // Emit EventCompleted event
+        EventCompleted::emit(&env, event_id, organizer, event.tickets_sold);
+
+        Ok(())
+    }
+
+    /// Release escrow funds after event completion. Only the organizer can release,
+    /// and events published with escrow signers also need their quorum.
+    pub fn release_escrow(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<i128, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if event.status != EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        let escrow_balance = storage::get_escrow(&env, event_id)?;
+
+        if escrow_balance == 0 {
+            return Err(LumentixError::EscrowAlreadyReleased);
+        }
+
+        Self::consume_escrow_quorum(
+            &env,
+            event_id,
+            EscrowAction::Release,
+            &organizer,
+            escrow_balance,
+        )?;
+
+        storage::clear_escrow(&env, event_id);
+
+        // Emit EscrowReleased event
+        EscrowReleased::emit(&env, event_id, organizer, escrow_balance);
+
+        Ok(escrow_balance)
+    }
+
+    /// Get the escrow balance for an event.
+    /// Returns 0 if no escrow exists (no tickets sold yet).
+    /// No auth required for transparency.
+    pub fn get_escrow_balance(env: Env, event_id: u64) -> Result<i128, LumentixError> {
+        // Verify event exists
+        let _ = storage::get_event(&env, event_id)?;
+
+        // Get escrow balance (returns 0 if no escrow key exists)
+        let balance = storage::get_escrow(&env, event_id)?;
+
+        Ok(balance)
+    }
+
+    /// Approve releasing or withdrawing `amount` of an event's escrow to `recipient`.
+    /// Only the event's escrow signers can approve; a new approval replaces the signer's
+    /// earlier one.
+    pub fn approve_escrow(
+        env: Env,
+        signer: Address,
+        event_id: u64,
+        action: EscrowAction,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        signer.require_auth();
+
+        if amount <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        let _ = storage::get_event(&env, event_id)?;
+        let config = storage::get_escrow_signers(&env, event_id)
+            .ok_or(LumentixError::EscrowConfigNotFound)?;
+        if !config.signers.contains(&signer) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_escrow_approval(
+            &env,
+            event_id,
+            &signer,
+            &EscrowApproval {
+                action,
+                recipient: recipient.clone(),
+                amount,
+            },
+        );
 
-        // Emit EventCompleted event
-        EventCompleted::emit(&env, event_id, organizer, event.tickets_sold);
+        // Emit EscrowApproved event
+        EscrowApproved::emit(&env, event_id, signer, recipient, amount);
 
         Ok(())
     }
 
-    /// Release escrow funds after event completion. Only the organizer can release.
-    pub fn release_escrow(
+    /// Revoke a signer's outstanding escrow approval.
+    /// Only the event's escrow signers can revoke.
+    pub fn revoke_escrow_approval(
         env: Env,
-        organizer: Address,
+        signer: Address,
         event_id: u64,
-    ) -> Result<i128, LumentixError> {
-        organizer.require_auth();
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
 
-        let event = storage::get_event(&env, event_id)?;
+        signer.require_auth();
 
-        if event.organizer != organizer {
+        let _ = storage::get_event(&env, event_id)?;
+        let config = storage::get_escrow_signers(&env, event_id)
+            .ok_or(LumentixError::EscrowConfigNotFound)?;
+        if !config.signers.contains(&signer) {
             return Err(LumentixError::Unauthorized);
         }
 
-        if event.status != EventStatus::Completed {
-            return Err(LumentixError::InvalidStatusTransition);
-        }
-
-        let escrow_balance = storage::get_escrow(&env, event_id)?;
-
-        if escrow_balance == 0 {
-            return Err(LumentixError::EscrowAlreadyReleased);
+        if storage::get_escrow_approval(&env, event_id, &signer).is_none() {
+            return Err(LumentixError::EscrowApprovalNotFound);
         }
 
-        storage::clear_escrow(&env, event_id);
+        storage::remove_escrow_approval(&env, event_id, &signer);
 
-        // Emit EscrowReleased event
-        EscrowReleased::emit(&env, event_id, organizer, escrow_balance);
+        // Emit EscrowApprovalRevoked event
+        EscrowApprovalRevoked::emit(&env, event_id, signer);
 
-        Ok(escrow_balance)
+        Ok(())
     }
 
-    /// Get the escrow balance for an event.
-    /// Returns 0 if no escrow exists (no tickets sold yet).
-    /// No auth required for transparency.
-    pub fn get_escrow_balance(env: Env, event_id: u64) -> Result<i128, LumentixError> {
-        // Verify event exists
+    /// Get the escrow signer set attached to an event at publish time.
+    pub fn get_escrow_signers(env: Env, event_id: u64) -> Result<EscrowSigners, LumentixError> {
         let _ = storage::get_event(&env, event_id)?;
+        storage::get_escrow_signers(&env, event_id).ok_or(LumentixError::EscrowConfigNotFound)
+    }
 
-        // Get escrow balance (returns 0 if no escrow key exists)
-        let balance = storage::get_escrow(&env, event_id)?;
-
-        Ok(balance)
+    /// Get a signer's outstanding escrow approval, if any.
+    pub fn get_escrow_approval(env: Env, event_id: u64, signer: Address) -> Option<EscrowApproval> {
+        storage::get_escrow_approval(&env, event_id, &signer)
     }
 
     /// Get event data by ID.
@@ -588,6 +2152,18 @@ impl LumentixContract {
         storage::get_event(&env, event_id)
     }
 
+    /// Get the compact on-chain fields of an event, without its name, description
+    /// and location strings. Rich content is fetched from `metadata_uri` and
+    /// verified against `content_hash` off-chain.
+    pub fn get_event_summary(env: Env, event_id: u64) -> Result<EventSummary, LumentixError> {
+        Ok(storage::get_event(&env, event_id)?.into())
+    }
+
+    /// Get the compact summary kept for an archived event.
+    pub fn get_archived_event(env: Env, event_id: u64) -> Result<EventSummary, LumentixError> {
+        storage::get_archived_event(&env, event_id)
+    }
+
     /// Get the status of an event by ID.
     /// Returns only the EventStatus without fetching the entire Event struct.
     /// Returns LumentixError::EventNotFound if the event doesn't exist.
@@ -601,7 +2177,9 @@ impl LumentixContract {
     /// Returns 0 if no events have been created yet.
     /// No auth required.
     pub fn get_total_events(env: Env) -> u64 {
-        storage::get_next_event_id(&env).saturating_sub(1)
+        storage::get_next_event_id(&env)
+            .saturating_sub(1)
+            .saturating_sub(storage::get_removed_event_count(&env))
     }
 
     /// Get all events created by a specific organizer.
@@ -625,8 +2203,9 @@ impl LumentixContract {
 
     /// Get all events created by a specific organizer with a specific status.
     /// Returns an empty vector if no events match.
-    /// No auth required.
-    pub fn get_events_by_organizer_and_status(
+    /// No auth required. Contract function names are limited to 32 characters, so
+    /// this replaces `get_events_by_organizer_and_status`.
+    pub fn get_events_by_org_status(
         env: Env,
         organizer: Address,
         status: EventStatus,
@@ -668,6 +2247,68 @@ impl LumentixContract {
         active_events
     }
 
+    /// Get the published events among a page of a category index. The page covers up
+    /// to `limit` indexed events (at most `MAX_PAGE_SIZE`) starting at slot `start`, so
+    /// it may hold fewer published events than `limit`.
+    pub fn get_active_events_by_category(
+        env: Env,
+        category: EventCategory,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Event> {
+        let index = EventIndex::Category(category);
+        Self::published_events(
+            &env,
+            storage::get_index_page(&env, &index, start, limit.min(MAX_PAGE_SIZE)),
+        )
+    }
+
+    /// Get the published events among a page of a tag index. The page covers up to
+    /// `limit` indexed events (at most `MAX_PAGE_SIZE`) starting at slot `start`.
+    pub fn get_active_events_by_tag(env: Env, tag: Symbol, start: u32, limit: u32) -> Vec<Event> {
+        let index = EventIndex::Tag(tag);
+        Self::published_events(
+            &env,
+            storage::get_index_page(&env, &index, start, limit.min(MAX_PAGE_SIZE)),
+        )
+    }
+
+    /// Get the published events starting within the next `days` days among a page of
+    /// the start day indexes, earliest day first. `days` is capped at `MAX_UPCOMING_DAYS`.
+    /// The page covers up to `limit` indexed events (at most `MAX_PAGE_SIZE`) starting
+    /// at slot `start` of the days' indexes taken in order, so it may hold fewer
+    /// upcoming events than `limit`.
+    pub fn get_upcoming_events(env: Env, days: u32, start: u32, limit: u32) -> Vec<Event> {
+        let now = env.ledger().timestamp();
+        let days = days.min(MAX_UPCOMING_DAYS) as u64;
+        let until = now.saturating_add(days * SECONDS_PER_DAY);
+
+        let mut upcoming = Vec::new(&env);
+        let mut skip = start;
+        let mut remaining = limit.min(MAX_PAGE_SIZE);
+        for day in now / SECONDS_PER_DAY..=until / SECONDS_PER_DAY {
+            if remaining == 0 {
+                break;
+            }
+            let index = EventIndex::StartDay(day);
+            let size = storage::get_index_size(&env, &index);
+            if skip >= size {
+                skip -= size;
+                continue;
+            }
+
+            let event_ids = storage::get_index_page(&env, &index, skip, remaining);
+            skip = 0;
+            remaining -= event_ids.len();
+            for event in Self::published_events(&env, event_ids).iter() {
+                if event.start_time >= now && event.start_time <= until {
+                    upcoming.push_back(event);
+                }
+            }
+        }
+        upcoming
+    }
+
     /// Get ticket data by ID.
     pub fn get_ticket_info(env: Env, ticket_id: u64) -> Result<Ticket, LumentixError> {
         storage::get_ticket(&env, ticket_id)
@@ -690,16 +2331,10 @@ impl LumentixContract {
         let _ = storage::get_event(&env, event_id)?;
 
         let mut tickets = Vec::new(&env);
-        let next_ticket_id = storage::get_next_ticket_id(&env);
-        let mut ticket_id: u64 = 1;
-
-        while ticket_id < next_ticket_id {
+        for ticket_id in storage::get_event_ticket_ids(&env, event_id).iter() {
             if let Ok(ticket) = storage::get_ticket(&env, ticket_id) {
-                if ticket.event_id == event_id {
-                    tickets.push_back(ticket);
-                }
+                tickets.push_back(ticket);
             }
-            ticket_id += 1;
         }
 
         Ok(tickets)
@@ -717,16 +2352,12 @@ impl LumentixContract {
         let _ = storage::get_event(&env, event_id)?;
 
         let mut tickets = Vec::new(&env);
-        let next_ticket_id = storage::get_next_ticket_id(&env);
-        let mut ticket_id: u64 = 1;
-
-        while ticket_id < next_ticket_id {
+        for ticket_id in storage::get_event_ticket_ids(&env, event_id).iter() {
             if let Ok(ticket) = storage::get_ticket(&env, ticket_id) {
-                if ticket.event_id == event_id && ticket.refunded {
+                if ticket.refunded {
                     tickets.push_back(ticket);
                 }
             }
-            ticket_id += 1;
         }
 
         Ok(tickets)
@@ -749,60 +2380,129 @@ impl LumentixContract {
         tickets
     }
 
-    /// Get all refunded tickets for a specific event.
-    /// Useful for organizers to track refund activity and for auditing after event cancellation.
-    /// Returns an empty Vec if no refunded tickets exist for the event.
-    pub fn get_refunded_tickets_by_event(
-        env: Env,
-        event_id: u64,
-    ) -> Result<Vec<Ticket>, LumentixError> {
-        // Verify the event exists
-        let _ = storage::get_event(&env, event_id)?;
-
-        let mut refunded_tickets = Vec::new(&env);
-        let next_ticket_id = storage::get_next_ticket_id(&env);
-        let mut ticket_id: u64 = 1;
-
-        // Iterate through all tickets
-        while ticket_id < next_ticket_id {
-            if let Ok(ticket) = storage::get_ticket(&env, ticket_id) {
-                // Check if ticket belongs to this event and is refunded
-                if ticket.event_id == event_id && ticket.refunded {
-                    refunded_tickets.push_back(ticket);
-                }
-            }
-            ticket_id += 1;
-        }
-
-        Ok(refunded_tickets)
-    }
-
-    /// Extend the TTL of an event. Only the organizer can call this.
+    /// Extend the TTL of an event, its escrow and the contract instance. Only the
+    /// organizer can call this.
     pub fn bump_event_ttl(env: Env, event_id: u64) -> Result<(), LumentixError> {
         let event = storage::get_event(&env, event_id)?;
 
         // Require authorization from the organizer
         event.organizer.require_auth();
 
-        // Accessing storage via `get_event` automatically extends TTL based on storage.rs logic.
+        storage::extend_event_ttl(&env, event_id, PERSISTENT_LIFETIME);
+        storage::extend_instance(&env);
         Ok(())
     }
 
-    /// Extend the TTL of a ticket to prevent expiration before the event.
-    /// No authorization required as this is a maintenance operation.
+    /// Extend the TTL of a ticket and the contract instance to prevent expiration
+    /// before the event. No authorization required as this is a maintenance operation.
     pub fn bump_ticket_ttl(env: Env, ticket_id: u64) -> Result<(), LumentixError> {
         // Read the ticket to verify it exists
         let _ticket = storage::get_ticket(&env, ticket_id)?;
 
-        // Extend the TTL for the ticket storage key
-        let key = ("TICKET_", ticket_id);
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
-
+        storage::extend_ticket_ttl(&env, ticket_id, PERSISTENT_LIFETIME);
+        storage::extend_instance(&env);
         Ok(())
     }
 
+    /// Keep the contract instance, an event, its escrow and a page of its tickets live
+    /// until at least `end_time` plus the dispute buffer. A run covers up to `limit`
+    /// tickets (at most `MAX_PAGE_SIZE`) per call from the event's ticket index: `start`
+    /// 0 begins a new run and later pages must start at the returned `next_index`. The
+    /// guaranteed live-until ledger is recorded for `get_events_near_expiry` once the
+    /// run is done.
+    /// No authorization required as this is a maintenance operation.
+    pub fn keep_event_alive(
+        env: Env,
+        event_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<KeepAliveProgress, LumentixError> {
+        let event = storage::get_event(&env, event_id)?;
+
+        // Later pages keep the guarantee of the page that began the run
+        let cursor = storage::get_keep_alive_cursor(&env, event_id);
+        let run_until = match cursor {
+            _ if start == 0 => None,
+            Some((next_index, live_until)) if next_index == start => Some(live_until),
+            _ => return Err(LumentixError::InvalidKeepAliveCursor),
+        };
+
+        let required_until = event.end_time.saturating_add(DISPUTE_BUFFER);
+        let remaining = required_until.saturating_sub(env.ledger().timestamp());
+        let needed = remaining.div_ceil(SECONDS_PER_LEDGER);
+        let ledgers = (needed.min(u32::MAX as u64) as u32)
+            .saturating_add(TEMPORARY_LIFETIME)
+            .max(PERSISTENT_LIFETIME)
+            .min(env.storage().max_ttl());
+
+        // Extensions leave entries within a day of the target alone, so only
+        // `ledgers` less that margin is guaranteed
+        let live_until_ledger = run_until.unwrap_or_else(|| {
+            env.ledger()
+                .sequence()
+                .saturating_add(ledgers.saturating_sub(TEMPORARY_LIFETIME))
+        });
+
+        storage::extend_instance_for(&env, ledgers);
+        storage::extend_event_ttl(&env, event_id, ledgers);
+        let next_index = storage::extend_event_tickets_ttl(
+            &env,
+            event_id,
+            start,
+            limit.min(MAX_PAGE_SIZE),
+            ledgers,
+        );
+
+        let done = next_index >= storage::get_event_ticket_count(&env, event_id);
+        if done {
+            storage::remove_keep_alive_cursor(&env, event_id);
+            storage::set_keep_alive_ledger(&env, event_id, live_until_ledger);
+        } else {
+            storage::set_keep_alive_cursor(&env, event_id, next_index, live_until_ledger);
+        }
+
+        Ok(KeepAliveProgress {
+            next_index,
+            live_until_ledger,
+            done,
+        })
+    }
+
+    /// List events among ids `start_id` to `start_id + limit` (at most `MAX_PAGE_SIZE`)
+    /// whose last complete keeper run does not guarantee them live for the next
+    /// `within_ledgers` ledgers, including events the keeper has never finished.
+    /// Contracts cannot read entry TTLs, so this reports the keeper's bookkeeping:
+    /// records extended by other means may live longer than reported.
+    pub fn get_events_near_expiry(
+        env: Env,
+        within_ledgers: u32,
+        start_id: u64,
+        limit: u32,
+    ) -> Vec<ExpiringEvent> {
+        let horizon = env.ledger().sequence().saturating_add(within_ledgers);
+        let mut expiring = Vec::new(&env);
+
+        let end_id = start_id
+            .max(1)
+            .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+            .min(storage::get_next_event_id(&env));
+        for event_id in start_id.max(1)..end_id {
+            let Ok(event) = storage::get_event(&env, event_id) else {
+                continue;
+            };
+            let live_until_ledger = storage::get_keep_alive_ledger(&env, event_id).unwrap_or(0);
+            if live_until_ledger < horizon {
+                expiring.push_back(ExpiringEvent {
+                    event_id,
+                    live_until_ledger,
+                    required_until: event.end_time.saturating_add(DISPUTE_BUFFER),
+                });
+            }
+        }
+
+        expiring
+    }
+
     /// Get the number of remaining tickets available for an event.
     /// Returns max_tickets - tickets_sold.
     pub fn get_availability(env: Env, event_id: u64) -> Result<u32, LumentixError> {
@@ -813,6 +2513,8 @@ impl LumentixContract {
     /// Set the platform fee in basis points (e.g., 250 = 2.5%).
     /// Only the admin can set the platform fee. Must be between 0 and 10000.
     pub fn set_platform_fee(env: Env, admin: Address, fee_bps: u32) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         admin.require_auth();
 
         let stored_admin = storage::get_admin(&env);
@@ -835,6 +2537,36 @@ impl LumentixContract {
         Ok(())
     }
 
+    /// Set whether refunds on cancelled events also return the platform fee.
+    /// Only the admin can set the refund policy.
+    pub fn set_refund_policy(
+        env: Env,
+        admin: Address,
+        policy: RefundPolicy,
+    ) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
+        admin.require_auth();
+
+        let stored_admin = storage::get_admin(&env);
+        if stored_admin != admin {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let old_policy = storage::get_refund_policy(&env);
+        storage::set_refund_policy(&env, policy);
+
+        // Emit RefundPolicyUpdated event
+        RefundPolicyUpdated::emit(&env, admin, old_policy, policy);
+
+        Ok(())
+    }
+
+    /// Get the refund policy applied to cancelled events.
+    pub fn get_refund_policy(env: Env) -> RefundPolicy {
+        storage::get_refund_policy(&env)
+    }
+
     /// Returns the configured **protocol (platform) fee** and the **fee recipient** used for ticket flows.
     ///
     /// The fee is expressed in **basis points** (bps): `1_000` bps = 10%, `10_000` bps = 100%. The recipient is
@@ -910,6 +2642,8 @@ impl LumentixContract {
         event_id: u64,
         amount: i128,
     ) -> Result<i128, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         depositor.require_auth();
 
         if !storage::is_initialized(&env) {
@@ -948,12 +2682,15 @@ impl LumentixContract {
     /// The withdrawer must be the event organizer or the admin.
     /// The event must exist and not be cancelled.
     /// Amount must be positive and not exceed available escrow balance.
+    /// Events published with escrow signers also need their quorum.
     pub fn withdraw_funds(
         env: Env,
         withdrawer: Address,
         event_id: u64,
         amount: i128,
     ) -> Result<i128, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         withdrawer.require_auth();
 
         if !storage::is_initialized(&env) {
@@ -984,6 +2721,8 @@ impl LumentixContract {
             return Err(LumentixError::InsufficientEscrow);
         }
 
+        Self::consume_escrow_quorum(&env, event_id, EscrowAction::Withdraw, &withdrawer, amount)?;
+
         // Deduct from escrow (treasury)
         storage::deduct_escrow(&env, event_id, amount)?;
         let new_balance = storage::get_escrow(&env, event_id)?;
@@ -996,6 +2735,8 @@ impl LumentixContract {
 
     /// Withdraw all accumulated platform fees. Only the admin can withdraw.
     pub fn withdraw_platform_fees(env: Env, admin: Address) -> Result<i128, LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         admin.require_auth();
 
         let stored_admin = storage::get_admin(&env);
@@ -1018,6 +2759,8 @@ impl LumentixContract {
 
     /// Set the payment token address. Only the admin can call this.
     pub fn set_token(env: Env, admin: Address, token: Address) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         admin.require_auth();
 
         if !storage::is_initialized(&env) {
@@ -1040,7 +2783,7 @@ impl LumentixContract {
             return Err(LumentixError::NotInitialized);
         }
 
-        if !env.storage().instance().has(&"TOKEN") {
+        if !storage::has_token(&env) {
             return Err(LumentixError::InvalidAddress);
         }
 
@@ -1062,6 +2805,8 @@ impl LumentixContract {
     /// Fails with Unauthorized if caller is not the current admin.
     /// Fails with InvalidAddress if new_admin is the same as current admin.
     pub fn change_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), LumentixError> {
+        migration::ensure_migrated(&env)?;
+
         admin.require_auth();
 
         let current_admin = storage::get_admin(&env);
@@ -1085,6 +2830,28 @@ impl LumentixContract {
         Ok(())
     }
 
+    /// Migrate up to `limit` records stored under the original layout's string-prefixed
+    /// keys to typed keys, continuing where the previous call stopped. Call repeatedly
+    /// until the returned phase is `Done`; state-changing entrypoints fail with
+    /// MigrationPending until then. Records that do not decode are skipped, reported
+    /// in `skipped` and left under their legacy keys. Only the admin can migrate.
+    pub fn migrate_storage(
+        env: Env,
+        admin: Address,
+        limit: u32,
+    ) -> Result<MigrationProgress, LumentixError> {
+        admin.require_auth();
+
+        Self::require_migration_admin(&env, &admin)?;
+
+        let progress = migration::migrate_batch(&env, limit);
+
+        // Emit StorageMigrated event
+        StorageMigrated::emit(&env, admin, progress.moved);
+
+        Ok(progress)
+    }
+
     /// Check if the contract has been initialized.
     /// Returns true if initialized, false otherwise.
     /// No auth required - useful for frontends and deployment scripts.
@@ -1093,22 +2860,16 @@ impl LumentixContract {
     }
 
     /// Get the addresses of all checked-in (used ticket) attendees for an event.
-    /// Verifies the event exists, then iterates all tickets collecting owners of
-    /// used tickets matching event_id. Deduplicates so each address appears once.
-    pub fn get_event_attendees(
-        env: Env,
-        event_id: u64,
-    ) -> Result<Vec<Address>, LumentixError> {
+    /// Verifies the event exists, then iterates the event's tickets collecting owners
+    /// of used tickets. Deduplicates so each address appears once.
+    pub fn get_event_attendees(env: Env, event_id: u64) -> Result<Vec<Address>, LumentixError> {
         // Verify event exists
         let _ = storage::get_event(&env, event_id)?;
 
         let mut attendees: Vec<Address> = Vec::new(&env);
-        let next_ticket_id = storage::get_next_ticket_id(&env);
-        let mut ticket_id: u64 = 1;
-
-        while ticket_id < next_ticket_id {
+        for ticket_id in storage::get_event_ticket_ids(&env, event_id).iter() {
             if let Ok(ticket) = storage::get_ticket(&env, ticket_id) {
-                if ticket.event_id == event_id && ticket.used {
+                if ticket.used {
                     // Deduplicate: only add if not already present
                     let mut already_added = false;
                     for i in 0..attendees.len() {
@@ -1122,9 +2883,215 @@ impl LumentixContract {
                     }
                 }
             }
-            ticket_id += 1;
         }
 
         Ok(attendees)
     }
 }
+
+impl LumentixContract {
+    /// Check that `admin` is the admin, whether or not the config has been migrated.
+    fn require_migration_admin(env: &Env, admin: &Address) -> Result<(), LumentixError> {
+        let stored_admin = match migration::legacy_admin(env) {
+            Some(legacy) => legacy,
+            None if storage::is_initialized(env) => storage::get_admin(env),
+            None => return Err(LumentixError::NotInitialized),
+        };
+        if stored_admin != *admin {
+            return Err(LumentixError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Start and end time of the `occurrence`-th (0-based) occurrence of a recurrence.
+    /// Fails with InvalidTimeRange when the times do not fit in a u64.
+    fn occurrence_times(
+        recurrence: &Recurrence,
+        duration: u64,
+        occurrence: u32,
+    ) -> Result<(u64, u64), LumentixError> {
+        let start_time = recurrence
+            .interval
+            .checked_mul(occurrence as u64)
+            .and_then(|offset| recurrence.start_time.checked_add(offset))
+            .ok_or(LumentixError::InvalidTimeRange)?;
+        let end_time = start_time
+            .checked_add(duration)
+            .ok_or(LumentixError::InvalidTimeRange)?;
+        Ok((start_time, end_time))
+    }
+
+    /// Hash binding a gift secret to the address claiming it: sha256(secret ‖ claimer XDR).
+    fn gift_claim_commitment(env: &Env, secret: &Bytes, claimer: &Address) -> BytesN<32> {
+        let mut preimage = secret.clone();
+        preimage.append(&claimer.clone().to_xdr(env));
+        env.crypto().sha256(&preimage).into()
+    }
+
+    /// Sell one ticket of a published event to `owner`, collecting the fee and escrow.
+    /// Checks capacity: rejects with EventSoldOut when tickets_sold >= max_tickets.
+    fn sell_ticket(
+        env: &Env,
+        owner: Address,
+        event_id: u64,
+        amount: i128,
+    ) -> Result<u64, LumentixError> {
+        let mut event = storage::get_event(env, event_id)?;
+
+        // Event must be published
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        // Check capacity — reject when sold out
+        if event.tickets_sold >= event.max_tickets {
+            return Err(LumentixError::EventSoldOut);
+        }
+
+        // Validate payment amount — overpayment is rejected rather than silently kept
+        fees::validate_exact_payment(amount, event.ticket_price)?;
+
+        // Split payment into platform fee and escrow
+        let fee_bps = storage::get_platform_fee_bps(env);
+        let fees::FeeSplit {
+            platform_fee,
+            escrow_amount,
+        } = fees::split_payment(amount, fee_bps);
+
+        // Collect platform fee
+        if platform_fee > 0 {
+            storage::add_platform_balance(env, platform_fee);
+        }
+
+        // Add to escrow
+        storage::add_escrow(env, event_id, escrow_amount);
+
+        // Increment tickets_sold counter
+        event.tickets_sold += 1;
+        storage::set_event(env, event_id, &event);
+
+        // Create ticket
+        let ticket_id = storage::get_next_ticket_id(env);
+        storage::increment_ticket_id(env);
+
+        let ticket = Ticket {
+            id: ticket_id,
+            event_id,
+            owner,
+            purchase_time: env.ledger().timestamp(),
+            used: false,
+            refunded: false,
+            paid_amount: amount,
+            platform_fee,
+            escrow_amount,
+            sessions: Vec::new(env),
+        };
+
+        storage::set_ticket(env, ticket_id, &ticket);
+        storage::add_event_ticket(env, event_id, ticket_id);
+        storage::increment_balance(env, &ticket.owner);
+
+        TicketPurchased::emit(
+            env,
+            ticket_id,
+            event_id,
+            ticket.owner,
+            amount,
+            platform_fee,
+            escrow_amount,
+        );
+
+        Ok(ticket_id)
+    }
+
+    /// Require the escrow signer quorum for an escrow movement, if the event has
+    /// signers, and consume the approvals once it is met.
+    fn consume_escrow_quorum(
+        env: &Env,
+        event_id: u64,
+        action: EscrowAction,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), LumentixError> {
+        let config = match storage::get_escrow_signers(env, event_id) {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+
+        let expected = EscrowApproval {
+            action,
+            recipient: recipient.clone(),
+            amount,
+        };
+        let approvals = config
+            .signers
+            .iter()
+            .filter(|signer| {
+                storage::get_escrow_approval(env, event_id, signer).as_ref() == Some(&expected)
+            })
+            .count() as u32;
+        if approvals < config.threshold {
+            return Err(LumentixError::ThresholdNotMet);
+        }
+
+        storage::clear_escrow_approvals(env, event_id, &config);
+        Ok(())
+    }
+
+    /// Verify one signed scan and admit its ticket at the signed timestamp.
+    fn admit_signed_scan(
+        env: &Env,
+        caller: &Address,
+        scan: &SignedScan,
+    ) -> Result<(), LumentixError> {
+        let mut ticket = storage::get_ticket(env, scan.ticket_id)?;
+        let event = storage::get_event(env, ticket.event_id)?;
+        if !admission::is_gate_agent(env, &event, caller) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        admission::verify_signed_scan(env, &ticket, scan)?;
+
+        let session_id = admission::admit(env, &mut ticket, &event, scan.timestamp)?;
+        storage::set_ticket(env, ticket.id, &ticket);
+        storage::set_last_scan_time(env, ticket.id, scan.timestamp);
+
+        if session_id != NO_SESSION {
+            SessionCheckedIn::emit(env, ticket.id, ticket.event_id, session_id);
+        }
+        TicketUsed::emit(
+            env,
+            ticket.id,
+            ticket.event_id,
+            ticket.owner,
+            caller.clone(),
+        );
+
+        Ok(())
+    }
+
+    /// Load the published events among `event_ids`.
+    fn published_events(env: &Env, event_ids: Vec<u64>) -> Vec<Event> {
+        let mut events = Vec::new(env);
+        for event_id in event_ids.iter() {
+            if let Ok(event) = storage::get_event(env, event_id) {
+                if event.status == EventStatus::Published {
+                    events.push_back(event);
+                }
+            }
+        }
+        events
+    }
+}
+
+impl LumentixContractClient<'_> {
+    /// Former name of `get_events_by_org_status`, kept for existing Rust callers.
+    #[deprecated(note = "use `get_events_by_org_status`")]
+    pub fn get_events_by_organizer_and_status(
+        &self,
+        organizer: &Address,
+        status: &EventStatus,
+    ) -> Vec<Event> {
+        self.get_events_by_org_status(organizer, status)
+    }
+}
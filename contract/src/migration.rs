@@ -0,0 +1,341 @@
+use crate::error::LumentixError;
+use crate::events::LegacyRecordSkipped;
+use crate::fees;
+use crate::storage::{self, DataKey};
+use crate::types::{
+    Event, EventCategory, EventStatus, MigrationPhase, MigrationProgress, Ticket, TransferPolicy,
+    DEFAULT_CHECKIN_WINDOW, NO_SERIES,
+};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
+
+// Instance keys of the original layout
+const LEGACY_INITIALIZED: &str = "INIT";
+const LEGACY_ADMIN: &str = "ADMIN";
+const LEGACY_TOKEN: &str = "TOKEN";
+const LEGACY_PLATFORM_FEE_BPS: &str = "PLATFORM_FEE_BPS";
+const LEGACY_EVENT_ID_COUNTER: &str = "EVENT_CTR";
+const LEGACY_TICKET_ID_COUNTER: &str = "TICKET_CTR";
+const LEGACY_PLATFORM_BALANCE: &str = "PLATFORM_BAL";
+
+// Persistent key prefixes of the original layout
+const LEGACY_EVENT_PREFIX: &str = "EVENT_";
+const LEGACY_TICKET_PREFIX: &str = "TICKET_";
+const LEGACY_ESCROW_PREFIX: &str = "ESCROW_";
+
+/// Event as stored by the original layout
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegacyEvent {
+    pub id: u64,
+    pub organizer: Address,
+    pub name: String,
+    pub description: String,
+    pub location: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub ticket_price: i128,
+    pub max_tickets: u32,
+    pub tickets_sold: u32,
+    pub status: EventStatus,
+}
+
+/// Ticket as stored by the original layout
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegacyTicket {
+    pub id: u64,
+    pub event_id: u64,
+    pub owner: Address,
+    pub purchase_time: u64,
+    pub used: bool,
+    pub refunded: bool,
+}
+
+const LEGACY_EVENT_FIELDS: [&str; 11] = [
+    "id",
+    "organizer",
+    "name",
+    "description",
+    "location",
+    "start_time",
+    "end_time",
+    "ticket_price",
+    "max_tickets",
+    "tickets_sold",
+    "status",
+];
+const LEGACY_TICKET_FIELDS: [&str; 6] = [
+    "id",
+    "event_id",
+    "owner",
+    "purchase_time",
+    "used",
+    "refunded",
+];
+
+/// Admin address as stored by the legacy layout, if it has not been migrated yet
+pub fn legacy_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&LEGACY_ADMIN)
+}
+
+/// Whether the legacy layout still holds config that `initialize` must not overwrite
+pub fn has_legacy_config(env: &Env) -> bool {
+    let instance = env.storage().instance();
+    instance.has(&LEGACY_INITIALIZED) || instance.has(&LEGACY_ADMIN)
+}
+
+/// Fail with MigrationPending while legacy records have not all been migrated.
+///
+/// Every state-changing entrypoint calls this first, so it also extends the
+/// instance TTL once per call.
+pub fn ensure_migrated(env: &Env) -> Result<(), LumentixError> {
+    if has_legacy_config(env) || env.storage().instance().has(&DataKey::MigrationCursor) {
+        return Err(LumentixError::MigrationPending);
+    }
+    storage::extend_instance(env);
+    Ok(())
+}
+
+/// Read a counter still held under its legacy instance key
+pub fn get_legacy_counter<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let old = match key {
+        DataKey::EventCounter => LEGACY_EVENT_ID_COUNTER,
+        DataKey::TicketCounter => LEGACY_TICKET_ID_COUNTER,
+        DataKey::PlatformBalance => LEGACY_PLATFORM_BALANCE,
+        _ => return None,
+    };
+    env.storage().instance().get(&old)
+}
+
+/// Migrate up to `limit` legacy records, continuing from the stored cursor. Config
+/// is migrated first, then events and tickets by ascending id. A record that does
+/// not decode is left under its legacy key, reported with `LegacyRecordSkipped` and
+/// counted in `skipped`. State-changing entrypoints stay blocked until the last
+/// phase completes.
+pub fn migrate_batch(env: &Env, limit: u32) -> MigrationProgress {
+    let (mut phase, mut next_id) = env
+        .storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(if has_legacy_config(env) {
+            (MigrationPhase::Config, 0)
+        } else {
+            (MigrationPhase::Done, 0)
+        });
+
+    let mut moved = 0;
+    let mut skipped = 0;
+    let mut remaining = limit;
+    while remaining > 0 && phase != MigrationPhase::Done {
+        let end = match phase {
+            MigrationPhase::Config => 1,
+            MigrationPhase::Events => storage::get_next_event_id(env),
+            MigrationPhase::Tickets => storage::get_next_ticket_id(env),
+            MigrationPhase::Done => 0,
+        };
+        if next_id >= end {
+            phase = next_phase(phase);
+            next_id = 1;
+            continue;
+        }
+
+        let migrated = match phase {
+            MigrationPhase::Config => Ok(migrate_config(env)),
+            MigrationPhase::Events => migrate_event(env, next_id),
+            MigrationPhase::Tickets => migrate_ticket(env, next_id),
+            MigrationPhase::Done => Ok(0),
+        };
+        match migrated {
+            Ok(count) => moved += count,
+            Err(_) => {
+                LegacyRecordSkipped::emit(env, phase, next_id);
+                skipped += 1;
+            }
+        }
+        next_id += 1;
+        remaining -= 1;
+    }
+
+    if phase == MigrationPhase::Done {
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+    } else {
+        env.storage()
+            .instance()
+            .set(&DataKey::MigrationCursor, &(phase, next_id));
+    }
+    storage::extend_instance(env);
+
+    MigrationProgress {
+        phase,
+        next_id,
+        moved,
+        skipped,
+    }
+}
+
+fn next_phase(phase: MigrationPhase) -> MigrationPhase {
+    match phase {
+        MigrationPhase::Config => MigrationPhase::Events,
+        MigrationPhase::Events => MigrationPhase::Tickets,
+        MigrationPhase::Tickets | MigrationPhase::Done => MigrationPhase::Done,
+    }
+}
+
+/// Move config to typed instance keys and counters to their own persistent entries
+fn migrate_config(env: &Env) -> u32 {
+    let mut moved = 0;
+    for (old, new) in [
+        (LEGACY_INITIALIZED, DataKey::Initialized),
+        (LEGACY_ADMIN, DataKey::Admin),
+        (LEGACY_TOKEN, DataKey::Token),
+        (LEGACY_PLATFORM_FEE_BPS, DataKey::PlatformFeeBps),
+    ] {
+        if let Some(value) = env.storage().instance().get::<_, Val>(&old) {
+            env.storage().instance().set(&new, &value);
+            env.storage().instance().remove(&old);
+            moved += 1;
+        }
+    }
+    for (old, new) in [
+        (LEGACY_EVENT_ID_COUNTER, DataKey::EventCounter),
+        (LEGACY_TICKET_ID_COUNTER, DataKey::TicketCounter),
+        (LEGACY_PLATFORM_BALANCE, DataKey::PlatformBalance),
+    ] {
+        if let Some(value) = env.storage().instance().get::<_, Val>(&old) {
+            set_persistent(env, &new, &value);
+            env.storage().instance().remove(&old);
+            moved += 1;
+        }
+    }
+    moved
+}
+
+/// Convert an event and move its escrow. An event that does not decode is left,
+/// with its escrow, under the legacy keys.
+fn migrate_event(env: &Env, event_id: u64) -> Result<u32, LumentixError> {
+    let mut moved = 0;
+
+    let old = (LEGACY_EVENT_PREFIX, event_id);
+    if let Some(value) = env.storage().persistent().get::<_, Val>(&old) {
+        let event = decode_event(env, &value)?;
+        storage::set_event(env, event_id, &event);
+        storage::index_event(env, &event);
+        env.storage().persistent().remove(&old);
+        moved += 1;
+    }
+
+    moved += move_entry(
+        env,
+        &(LEGACY_ESCROW_PREFIX, event_id),
+        &DataKey::Escrow(event_id),
+    );
+    Ok(moved)
+}
+
+/// Convert a ticket, index it under its event and count it for its owner
+fn migrate_ticket(env: &Env, ticket_id: u64) -> Result<u32, LumentixError> {
+    let old = (LEGACY_TICKET_PREFIX, ticket_id);
+    let Some(value) = env.storage().persistent().get::<_, Val>(&old) else {
+        return Ok(0);
+    };
+
+    let ticket = decode_ticket(env, &value)?;
+    storage::set_ticket(env, ticket_id, &ticket);
+    storage::add_event_ticket(env, ticket.event_id, ticket_id);
+    // The original layout kept no balances
+    if !ticket.refunded {
+        storage::increment_balance(env, &ticket.owner);
+    }
+    env.storage().persistent().remove(&old);
+    Ok(1)
+}
+
+/// Whether a stored value is a struct with exactly the given fields. Decoding a
+/// struct of the wrong shape traps in the host, so the shape is checked first.
+fn has_fields(env: &Env, value: &Val, names: &[&str]) -> bool {
+    let Ok(fields) = Map::<Symbol, Val>::try_from_val(env, value) else {
+        return false;
+    };
+    fields.len() == names.len() as u32
+        && names
+            .iter()
+            .all(|name| fields.contains_key(Symbol::new(env, name)))
+}
+
+/// Decode an original-layout event, filling the fields added since with the
+/// defaults new events get
+fn decode_event(env: &Env, value: &Val) -> Result<Event, LumentixError> {
+    if !has_fields(env, value, &LEGACY_EVENT_FIELDS) {
+        return Err(LumentixError::InvalidLegacyRecord);
+    }
+
+    let legacy =
+        LegacyEvent::try_from_val(env, value).map_err(|_| LumentixError::InvalidLegacyRecord)?;
+    Ok(Event {
+        id: legacy.id,
+        organizer: legacy.organizer,
+        name: legacy.name,
+        description: legacy.description,
+        location: legacy.location,
+        start_time: legacy.start_time,
+        end_time: legacy.end_time,
+        ticket_price: legacy.ticket_price,
+        max_tickets: legacy.max_tickets,
+        tickets_sold: legacy.tickets_sold,
+        status: legacy.status,
+        reentry_allowed: false,
+        checkin_window: DEFAULT_CHECKIN_WINDOW,
+        badges_enabled: false,
+        transfer_policy: TransferPolicy::Free,
+        metadata_uri: None,
+        content_hash: None,
+        category: EventCategory::Other,
+        tags: Vec::new(env),
+        series_id: NO_SERIES,
+    })
+}
+
+/// Decode an original-layout ticket. The original layout did not record what was
+/// paid, so a converted ticket is assumed to have paid its event's price, split at
+/// the current platform fee.
+fn decode_ticket(env: &Env, value: &Val) -> Result<Ticket, LumentixError> {
+    if !has_fields(env, value, &LEGACY_TICKET_FIELDS) {
+        return Err(LumentixError::InvalidLegacyRecord);
+    }
+
+    let legacy =
+        LegacyTicket::try_from_val(env, value).map_err(|_| LumentixError::InvalidLegacyRecord)?;
+    let paid_amount = storage::get_event(env, legacy.event_id)
+        .map(|event| event.ticket_price)
+        .unwrap_or(0);
+    let split = fees::split_payment(paid_amount, storage::get_platform_fee_bps(env));
+    Ok(Ticket {
+        id: legacy.id,
+        event_id: legacy.event_id,
+        owner: legacy.owner,
+        purchase_time: legacy.purchase_time,
+        used: legacy.used,
+        refunded: legacy.refunded,
+        paid_amount,
+        platform_fee: split.platform_fee,
+        escrow_amount: split.escrow_amount,
+        sessions: Vec::new(env),
+    })
+}
+
+/// Move a persistent entry from a legacy key to its typed key, if present
+fn move_entry<K: IntoVal<Env, Val>>(env: &Env, old: &K, new: &DataKey) -> u32 {
+    match env.storage().persistent().get::<_, Val>(old) {
+        Some(value) => {
+            set_persistent(env, new, &value);
+            env.storage().persistent().remove(old);
+            1
+        }
+        None => 0,
+    }
+}
+
+fn set_persistent(env: &Env, key: &DataKey, value: &Val) {
+    env.storage().persistent().set(key, value);
+    storage::extend_persistent(env, key);
+}
@@ -17,20 +17,24 @@ pub struct EventAuth {
     pub organizer: Address,
 }
 
-/// Storage key for authorized validators
-#[contracttype]
-#[derive(Clone)]
-pub struct ValidatorKey {
-    pub event_id: Symbol,
-    pub validator: Address,
-}
-
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Ticket(Symbol),
+    EventAuth(Symbol),
+    Validator(Symbol, Address),
     EscrowConfig(Symbol),
-    EscrowApproval(Symbol, Address),
+    ProposalCounter,
+    Proposal(u64),
+    OpenProposals(Symbol),
+    EscrowToken(Symbol),
+    EscrowBalance(Symbol),
+    BadgesEnabled(Symbol),
+    Badge(Symbol, Address),
+    HolderBadgeCount(Address),
+    HolderBadge(Address, u32),
+    BadgeHolderCount(Symbol),
+    BadgeHolder(Symbol, u32),
 }
 
 #[contracttype]
@@ -40,3 +44,38 @@ pub struct EscrowConfig {
     pub signers: Vec<Address>,
     pub threshold: u32,
 }
+
+/// Lifecycle of an escrow release proposal
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Open,
+    Executed,
+    Rejected,
+    Cancelled,
+}
+
+/// A proposal to release an amount of an event's escrow to a destination,
+/// with the signers that approved or rejected it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseProposal {
+    pub id: u64,
+    pub event_id: Symbol,
+    pub proposer: Address,
+    pub destination: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+    pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub status: ProposalStatus,
+}
+
+/// Non-transferable proof-of-attendance badge minted when a ticket is validated
+#[contracttype]
+#[derive(Clone)]
+pub struct AttendanceBadge {
+    pub event_id: Symbol,
+    pub holder: Address,
+    pub checked_in_at: u64,
+}
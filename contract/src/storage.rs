@@ -1,181 +1,262 @@
 use crate::error::LumentixError;
-use crate::types::{Event, Ticket, INSTANCE_LIFETIME, PERSISTENT_LIFETIME};
-use soroban_sdk::{Address, Env};
-
-// Storage keys
-const INITIALIZED: &str = "INIT";
-const ADMIN: &str = "ADMIN";
-const TOKEN: &str = "TOKEN";
-const EVENT_ID_COUNTER: &str = "EVENT_CTR";
-const TICKET_ID_COUNTER: &str = "TICKET_CTR";
-const EVENT_PREFIX: &str = "EVENT_";
-const TICKET_PREFIX: &str = "TICKET_";
-const ESCROW_PREFIX: &str = "ESCROW_";
-const PLATFORM_FEE_BPS: &str = "PLATFORM_FEE_BPS";
-const PLATFORM_BALANCE: &str = "PLATFORM_BAL";
+use crate::migration;
+use crate::types::{
+    AdmissionState, AttendanceBadge, Bundle, EscrowApproval, EscrowSigners, Event, EventCategory,
+    EventSeries, EventSummary, Gift, RefundPolicy, Session, Ticket, TransferRequest,
+    GIFT_CLAIM_WINDOW, INSTANCE_BUMP_THRESHOLD, INSTANCE_LIFETIME, NO_SESSION,
+    PERSISTENT_BUMP_THRESHOLD, PERSISTENT_LIFETIME, SECONDS_PER_DAY, TEMPORARY_LIFETIME,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+/// Storage keys of the contract.
+///
+/// Placement is deliberate: instance storage is loaded on every call, so it only
+/// holds the small, rarely written config most entrypoints read. Counters and the
+/// platform balance are written on hot paths and live in their own persistent
+/// entries, so each call only touches the ones it needs.
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    // Instance
+    Initialized,
+    Admin,
+    Token,
+    PlatformFeeBps,
+    RefundPolicy,
+    MigrationCursor,
+    // Persistent
+    EventCounter,
+    TicketCounter,
+    BundleCounter,
+    SeriesCounter,
+    RemovedEvents,
+    PlatformBalance,
+    // Persistent, one entry per record
+    Event(u64),
+    Escrow(u64),
+    Sessions(u64),
+    KeepAlive(u64),
+    KeepAliveCursor(u64),
+    Archive(u64),
+    Ticket(u64),
+    Admission(u64, u32),
+    EarlyEntry(u64),
+    PassKey(u64),
+    LastScan(u64),
+    Approval(u64),
+    TransferRequest(u64),
+    EscrowSigners(u64),
+    EscrowApproval(u64, Address),
+    Validator(u64, Address),
+    ValidatorCount(u64),
+    BundleRefCount(u64),
+    EventTicketCount(u64),
+    EventTicket(u64, u32),
+    Badge(u64, Address),
+    HolderBadgeCount(Address),
+    HolderBadge(Address, u32),
+    BadgeHolderCount(u64),
+    BadgeHolder(u64, u32),
+    Balance(Address),
+    Operator(Address, Address),
+    Gift(BytesN<32>),
+    GiftClaim(BytesN<32>),
+    Bundle(u64),
+    Series(u64),
+    IndexSize(EventIndex),
+    IndexSlot(EventIndex, u32),
+    IndexMember(EventIndex, u64),
+}
+
+/// Discovery indexes over event ids
+#[contracttype]
+#[derive(Clone)]
+pub enum EventIndex {
+    /// Events in a category other than the default `EventCategory::Other`
+    Category(EventCategory),
+    /// Events carrying a tag
+    Tag(Symbol),
+    /// Events starting on a day, as `start_time / SECONDS_PER_DAY`
+    StartDay(u64),
+}
+
+/// Extend the instance TTL once it falls below the bump threshold.
+///
+/// Reads never extend: state-changing entrypoints extend the instance once per call
+/// through `migration::ensure_migrated`, config writes extend it on write, and the
+/// TTL keepers extend it alongside the records they keep live.
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_BUMP_THRESHOLD, INSTANCE_LIFETIME);
+}
+
+/// Extend the instance to live `ledgers` more ledgers once it falls a day below
+/// that, so records kept live for `ledgers` never outlive the contract instance
+pub fn extend_instance_for(env: &Env, ledgers: u32) {
+    let threshold = ledgers.saturating_sub(TEMPORARY_LIFETIME);
+    env.storage().instance().extend_ttl(threshold, ledgers);
+}
+
+/// Extend a persistent entry to the full lifetime once it falls below the bump threshold.
+///
+/// Setters extend the entries they write and getters never extend, so a record is
+/// extended at most once per write and untouched by reads.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_BUMP_THRESHOLD, PERSISTENT_LIFETIME);
+}
+
+/// Extend a persistent entry to live `ledgers` more ledgers once it falls a day
+/// below that, the same margin as the bump threshold. The entry is guaranteed to
+/// live at least `ledgers - TEMPORARY_LIFETIME` more ledgers afterwards
+pub fn extend_persistent_for<K: IntoVal<Env, Val>>(env: &Env, key: &K, ledgers: u32) {
+    let threshold = ledgers.saturating_sub(TEMPORARY_LIFETIME);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, threshold, ledgers);
+}
+
+/// Read an instance config value
+fn get_instance<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    env.storage().instance().get(key)
+}
+
+/// Write an instance config value
+fn set_instance<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().instance().set(key, value);
+    extend_instance(env);
+}
+
+/// Read a persistent counter, falling back to its legacy instance key until
+/// `migrate_storage` has moved it
+fn get_counter<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .or_else(|| migration::get_legacy_counter(env, key))
+}
+
+/// Write a persistent counter
+fn set_counter<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
 
 /// Check if contract is initialized
 pub fn is_initialized(env: &Env) -> bool {
-    let has = env.storage().instance().has(&INITIALIZED);
-    if has {
-        env.storage()
-            .instance()
-            .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    }
-    has
+    get_instance::<bool>(env, &DataKey::Initialized).unwrap_or(false)
 }
 
 /// Mark contract as initialized
 pub fn set_initialized(env: &Env) {
-    env.storage().instance().set(&INITIALIZED, &true);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    set_instance(env, &DataKey::Initialized, &true);
 }
 
 /// Set admin address
 pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&ADMIN, admin);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    set_instance(env, &DataKey::Admin, admin);
 }
 
 /// Get admin address
 pub fn get_admin(env: &Env) -> Address {
-    let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    admin
+    get_instance(env, &DataKey::Admin).unwrap()
 }
 
 /// Set token address
 pub fn set_token(env: &Env, token: &Address) {
-    env.storage().instance().set(&TOKEN, token);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    set_instance(env, &DataKey::Token, token);
+}
+
+/// Check whether a token address is configured
+pub fn has_token(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Token)
 }
 
 /// Get token address
 pub fn get_token(env: &Env) -> Address {
-    let token: Address = env.storage().instance().get(&TOKEN).unwrap();
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    token
+    get_instance(env, &DataKey::Token).unwrap()
 }
 
 /// Get next event ID
 pub fn get_next_event_id(env: &Env) -> u64 {
-    let id = env.storage().instance().get(&EVENT_ID_COUNTER).unwrap_or(1);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    id
+    get_counter(env, &DataKey::EventCounter).unwrap_or(1)
 }
 
 /// Increment event ID counter
 pub fn increment_event_id(env: &Env) {
     let next_id = get_next_event_id(env) + 1;
-    env.storage().instance().set(&EVENT_ID_COUNTER, &next_id);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    set_counter(env, &DataKey::EventCounter, &next_id);
 }
 
 /// Get next ticket ID
 pub fn get_next_ticket_id(env: &Env) -> u64 {
-    let id = env
-        .storage()
-        .instance()
-        .get(&TICKET_ID_COUNTER)
-        .unwrap_or(1);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    id
+    get_counter(env, &DataKey::TicketCounter).unwrap_or(1)
 }
 
 /// Increment ticket ID counter
 pub fn increment_ticket_id(env: &Env) {
     let next_id = get_next_ticket_id(env) + 1;
-    env.storage().instance().set(&TICKET_ID_COUNTER, &next_id);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    set_counter(env, &DataKey::TicketCounter, &next_id);
 }
 
 /// Set event data
 pub fn set_event(env: &Env, event_id: u64, event: &Event) {
-    let key = (EVENT_PREFIX, event_id);
+    let key = DataKey::Event(event_id);
     env.storage().persistent().set(&key, event);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    extend_persistent(env, &key);
 }
 
 /// Get event data
 pub fn get_event(env: &Env, event_id: u64) -> Result<Event, LumentixError> {
-    let key = (EVENT_PREFIX, event_id);
-    let event = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(LumentixError::EventNotFound)?;
     env.storage()
         .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
-    Ok(event)
+        .get(&DataKey::Event(event_id))
+        .ok_or(LumentixError::EventNotFound)
 }
 
 /// Set ticket data
+///
+/// New tickets of a kept-alive event inherit the event's guaranteed liveness.
 pub fn set_ticket(env: &Env, ticket_id: u64, ticket: &Ticket) {
-    let key = (TICKET_PREFIX, ticket_id);
+    let key = DataKey::Ticket(ticket_id);
     env.storage().persistent().set(&key, ticket);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    let ledgers = get_keep_alive_ledger(env, ticket.event_id)
+        .map(|until| until.saturating_sub(env.ledger().sequence()))
+        .unwrap_or(0)
+        .max(PERSISTENT_LIFETIME);
+    extend_persistent_for(env, &key, ledgers);
 }
 
 /// Get ticket data
 pub fn get_ticket(env: &Env, ticket_id: u64) -> Result<Ticket, LumentixError> {
-    let key = (TICKET_PREFIX, ticket_id);
-    let ticket = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(LumentixError::TicketNotFound)?;
     env.storage()
         .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
-    Ok(ticket)
+        .get(&DataKey::Ticket(ticket_id))
+        .ok_or(LumentixError::TicketNotFound)
 }
 
 /// Add amount to escrow for an event
 pub fn add_escrow(env: &Env, event_id: u64, amount: i128) {
-    let key = (ESCROW_PREFIX, event_id);
+    let key = DataKey::Escrow(event_id);
     let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
     env.storage().persistent().set(&key, &(current + amount));
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    extend_persistent(env, &key);
 }
 
 /// Get escrow balance for an event
 pub fn get_escrow(env: &Env, event_id: u64) -> Result<i128, LumentixError> {
-    let key = (ESCROW_PREFIX, event_id);
-    let bal: i128 = env.storage().persistent().get(&key).unwrap_or(0);
-    env.storage()
+    Ok(env
+        .storage()
         .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
-    Ok(bal)
+        .get(&DataKey::Escrow(event_id))
+        .unwrap_or(0))
 }
 
 /// Deduct amount from escrow
 pub fn deduct_escrow(env: &Env, event_id: u64, amount: i128) -> Result<(), LumentixError> {
-    let key = (ESCROW_PREFIX, event_id);
+    let key = DataKey::Escrow(event_id);
     let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
 
     if current < amount {
@@ -183,62 +264,780 @@ pub fn deduct_escrow(env: &Env, event_id: u64, amount: i128) -> Result<(), Lumen
     }
 
     env.storage().persistent().set(&key, &(current - amount));
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    extend_persistent(env, &key);
     Ok(())
 }
 
 /// Clear escrow for an event
 pub fn clear_escrow(env: &Env, event_id: u64) {
-    let key = (ESCROW_PREFIX, event_id);
+    let key = DataKey::Escrow(event_id);
     env.storage().persistent().set(&key, &0i128);
-    env.storage()
-        .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    extend_persistent(env, &key);
 }
 
 /// Set platform fee in basis points (e.g., 250 = 2.5%)
 pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
-    env.storage().instance().set(&PLATFORM_FEE_BPS, &fee_bps);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    set_instance(env, &DataKey::PlatformFeeBps, &fee_bps);
 }
 
 /// Get platform fee in basis points
 pub fn get_platform_fee_bps(env: &Env) -> u32 {
-    let fee = env.storage().instance().get(&PLATFORM_FEE_BPS).unwrap_or(0);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    fee
+    get_instance(env, &DataKey::PlatformFeeBps).unwrap_or(0)
 }
 
 /// Add amount to platform balance
 pub fn add_platform_balance(env: &Env, amount: i128) {
-    let current: i128 = env.storage().instance().get(&PLATFORM_BALANCE).unwrap_or(0);
-    env.storage()
-        .instance()
-        .set(&PLATFORM_BALANCE, &(current + amount));
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    let current = get_platform_balance(env);
+    set_counter(env, &DataKey::PlatformBalance, &(current + amount));
 }
 
 /// Get platform balance
 pub fn get_platform_balance(env: &Env) -> i128 {
-    let bal = env.storage().instance().get(&PLATFORM_BALANCE).unwrap_or(0);
-    env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
-    bal
+    get_counter(env, &DataKey::PlatformBalance).unwrap_or(0)
+}
+
+/// Deduct amount from platform balance
+pub fn deduct_platform_balance(env: &Env, amount: i128) -> Result<(), LumentixError> {
+    let current = get_platform_balance(env);
+
+    if current < amount {
+        return Err(LumentixError::InsufficientPlatformBalance);
+    }
+
+    set_counter(env, &DataKey::PlatformBalance, &(current - amount));
+    Ok(())
 }
 
 /// Clear platform balance (after withdrawal)
 pub fn clear_platform_balance(env: &Env) {
-    env.storage().instance().set(&PLATFORM_BALANCE, &0i128);
+    set_counter(env, &DataKey::PlatformBalance, &0i128);
+}
+
+/// Set the refund policy applied to cancelled events
+pub fn set_refund_policy(env: &Env, policy: RefundPolicy) {
+    set_instance(env, &DataKey::RefundPolicy, &policy);
+}
+
+/// Get the refund policy (defaults to EscrowOnly)
+pub fn get_refund_policy(env: &Env) -> RefundPolicy {
+    get_instance(env, &DataKey::RefundPolicy).unwrap_or(RefundPolicy::EscrowOnly)
+}
+
+/// Get next bundle ID
+pub fn get_next_bundle_id(env: &Env) -> u64 {
+    get_counter(env, &DataKey::BundleCounter).unwrap_or(1)
+}
+
+/// Increment bundle ID counter
+pub fn increment_bundle_id(env: &Env) {
+    let next_id = get_next_bundle_id(env) + 1;
+    set_counter(env, &DataKey::BundleCounter, &next_id);
+}
+
+/// Set bundle data
+pub fn set_bundle(env: &Env, bundle_id: u64, bundle: &Bundle) {
+    let key = DataKey::Bundle(bundle_id);
+    env.storage().persistent().set(&key, bundle);
+    extend_persistent(env, &key);
+}
+
+/// Get bundle data
+pub fn get_bundle(env: &Env, bundle_id: u64) -> Result<Bundle, LumentixError> {
     env.storage()
-        .instance()
-        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+        .persistent()
+        .get(&DataKey::Bundle(bundle_id))
+        .ok_or(LumentixError::BundleNotFound)
+}
+
+/// Set the sessions of an event
+pub fn set_sessions(env: &Env, event_id: u64, sessions: &Vec<Session>) {
+    let key = DataKey::Sessions(event_id);
+    env.storage().persistent().set(&key, sessions);
+    extend_persistent(env, &key);
+}
+
+/// Get the sessions of an event (empty if the event has none)
+pub fn get_sessions(env: &Env, event_id: u64) -> Vec<Session> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Sessions(event_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the admission state of a ticket for a session
+pub fn set_admission(env: &Env, ticket_id: u64, session_id: u32, state: AdmissionState) {
+    let key = DataKey::Admission(ticket_id, session_id);
+    env.storage().persistent().set(&key, &state);
+    extend_persistent(env, &key);
+}
+
+/// Get the admission state of a ticket for a session, if it has ever checked in
+pub fn get_admission(env: &Env, ticket_id: u64, session_id: u32) -> Option<AdmissionState> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Admission(ticket_id, session_id))
+}
+
+/// Grant or revoke early entry for a ticket
+pub fn set_early_entry(env: &Env, ticket_id: u64, allowed: bool) {
+    let key = DataKey::EarlyEntry(ticket_id);
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether a ticket has been granted early entry
+pub fn has_early_entry(env: &Env, ticket_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::EarlyEntry(ticket_id))
+}
+
+/// Set the entry pass public key of a ticket
+pub fn set_pass_key(env: &Env, ticket_id: u64, public_key: &BytesN<32>) {
+    let key = DataKey::PassKey(ticket_id);
+    env.storage().persistent().set(&key, public_key);
+    extend_persistent(env, &key);
+}
+
+/// Get the entry pass public key of a ticket, if registered
+pub fn get_pass_key(env: &Env, ticket_id: u64) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&DataKey::PassKey(ticket_id))
+}
+
+/// Remove the entry pass public key of a ticket
+pub fn remove_pass_key(env: &Env, ticket_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PassKey(ticket_id));
+}
+
+/// Record the timestamp of the last accepted signed scan of a ticket
+pub fn set_last_scan_time(env: &Env, ticket_id: u64, timestamp: u64) {
+    let key = DataKey::LastScan(ticket_id);
+    env.storage().persistent().set(&key, &timestamp);
+    extend_persistent(env, &key);
+}
+
+/// Get the timestamp of the last accepted signed scan of a ticket
+pub fn get_last_scan_time(env: &Env, ticket_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastScan(ticket_id))
+}
+
+/// Authorize or deauthorize a validator (gate agent) for an event
+pub fn set_validator(env: &Env, event_id: u64, validator: &Address, authorized: bool) {
+    let key = DataKey::Validator(event_id, validator.clone());
+    let count = get_validator_count(env, event_id);
+    let present = env.storage().persistent().has(&key);
+    if authorized {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(env, &key);
+        if !present {
+            set_index_entry(env, &DataKey::ValidatorCount(event_id), &(count + 1));
+        }
+    } else if present {
+        env.storage().persistent().remove(&key);
+        set_index_entry(env, &DataKey::ValidatorCount(event_id), &(count - 1));
+    }
+}
+
+/// Get the number of authorized validators of an event
+pub fn get_validator_count(env: &Env, event_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ValidatorCount(event_id))
+        .unwrap_or(0)
+}
+
+/// Count one more bundle referencing an event
+pub fn add_bundle_ref(env: &Env, event_id: u64) {
+    let count = get_bundle_ref_count(env, event_id);
+    set_index_entry(env, &DataKey::BundleRefCount(event_id), &(count + 1));
+}
+
+/// Get the number of bundles referencing an event
+pub fn get_bundle_ref_count(env: &Env, event_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BundleRefCount(event_id))
+        .unwrap_or(0)
+}
+
+/// Append a ticket to the ticket index of its event
+pub fn add_event_ticket(env: &Env, event_id: u64, ticket_id: u64) {
+    let count = get_event_ticket_count(env, event_id);
+    set_index_entry(env, &DataKey::EventTicket(event_id, count), &ticket_id);
+    set_index_entry(env, &DataKey::EventTicketCount(event_id), &(count + 1));
+}
+
+/// Get the number of tickets ever issued for an event
+pub fn get_event_ticket_count(env: &Env, event_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventTicketCount(event_id))
+        .unwrap_or(0)
+}
+
+/// Get the ids of every ticket issued for an event, in issue order
+pub fn get_event_ticket_ids(env: &Env, event_id: u64) -> Vec<u64> {
+    let mut ticket_ids = Vec::new(env);
+    for index in 0..get_event_ticket_count(env, event_id) {
+        if let Some(ticket_id) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EventTicket(event_id, index))
+        {
+            ticket_ids.push_back(ticket_id);
+        }
+    }
+    ticket_ids
+}
+
+/// Check whether an address is an authorized validator for an event
+pub fn is_validator(env: &Env, event_id: u64, validator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Validator(event_id, validator.clone()))
+}
+
+/// Store an attendance badge and index it by holder and by event
+pub fn add_badge(env: &Env, badge: &AttendanceBadge) {
+    let key = DataKey::Badge(badge.event_id, badge.holder.clone());
+    env.storage().persistent().set(&key, badge);
+    extend_persistent(env, &key);
+
+    index_badge(env, badge.event_id, &badge.holder);
+}
+
+/// Append a badge to the holder's and the event's badge indexes. Each index entry
+/// is its own key, so minting never rewrites a list that grows with attendance.
+pub fn index_badge(env: &Env, event_id: u64, holder: &Address) {
+    let holder_count = get_holder_badge_count(env, holder);
+    set_index_entry(
+        env,
+        &DataKey::HolderBadge(holder.clone(), holder_count),
+        &event_id,
+    );
+    set_index_entry(
+        env,
+        &DataKey::HolderBadgeCount(holder.clone()),
+        &(holder_count + 1),
+    );
+
+    let holders_count = get_badge_holder_count(env, event_id);
+    set_index_entry(env, &DataKey::BadgeHolder(event_id, holders_count), holder);
+    set_index_entry(
+        env,
+        &DataKey::BadgeHolderCount(event_id),
+        &(holders_count + 1),
+    );
+}
+
+/// Check whether a holder already has the attendance badge of an event
+pub fn has_badge(env: &Env, event_id: u64, holder: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Badge(event_id, holder.clone()))
+}
+
+/// Get the number of attendance badges of a holder
+pub fn get_holder_badge_count(env: &Env, holder: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderBadgeCount(holder.clone()))
+        .unwrap_or(0)
+}
+
+/// Get up to `limit` attendance badges of a holder, starting at index `start`
+pub fn get_badges(env: &Env, holder: &Address, start: u32, limit: u32) -> Vec<AttendanceBadge> {
+    let end = get_holder_badge_count(env, holder).min(start.saturating_add(limit));
+    let mut badges = Vec::new(env);
+    for index in start..end {
+        let event_id: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HolderBadge(holder.clone(), index));
+        if let Some(badge) = event_id.and_then(|event_id| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::Badge(event_id, holder.clone()))
+        }) {
+            badges.push_back(badge);
+        }
+    }
+    badges
+}
+
+/// Get the number of holders of an event's attendance badge
+pub fn get_badge_holder_count(env: &Env, event_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BadgeHolderCount(event_id))
+        .unwrap_or(0)
+}
+
+/// Get up to `limit` holders of an event's attendance badge, starting at index `start`
+pub fn get_badge_holders(env: &Env, event_id: u64, start: u32, limit: u32) -> Vec<Address> {
+    let end = get_badge_holder_count(env, event_id).min(start.saturating_add(limit));
+    let mut holders = Vec::new(env);
+    for index in start..end {
+        if let Some(holder) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BadgeHolder(event_id, index))
+        {
+            holders.push_back(holder);
+        }
+    }
+    holders
+}
+
+/// Get the number of tickets owned by an address
+pub fn get_balance(env: &Env, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(owner.clone()))
+        .unwrap_or(0)
+}
+
+fn set_balance(env: &Env, owner: &Address, balance: u32) {
+    let key = DataKey::Balance(owner.clone());
+    env.storage().persistent().set(&key, &balance);
+    extend_persistent(env, &key);
+}
+
+/// Count one more ticket owned by an address
+pub fn increment_balance(env: &Env, owner: &Address) {
+    set_balance(env, owner, get_balance(env, owner) + 1);
+}
+
+/// Count one less ticket owned by an address
+pub fn decrement_balance(env: &Env, owner: &Address) {
+    set_balance(env, owner, get_balance(env, owner).saturating_sub(1));
+}
+
+/// Set the address approved to transfer a single ticket
+pub fn set_ticket_approval(env: &Env, ticket_id: u64, approved: &Address) {
+    let key = DataKey::Approval(ticket_id);
+    env.storage().persistent().set(&key, approved);
+    extend_persistent(env, &key);
+}
+
+/// Get the address approved to transfer a single ticket, if any
+pub fn get_ticket_approval(env: &Env, ticket_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Approval(ticket_id))
+}
+
+/// Clear the approval of a single ticket
+pub fn remove_ticket_approval(env: &Env, ticket_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Approval(ticket_id));
+}
+
+/// Approve or revoke an operator for all tickets of an owner
+pub fn set_operator(env: &Env, owner: &Address, operator: &Address, approved: bool) {
+    let key = DataKey::Operator(owner.clone(), operator.clone());
+    if approved {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Check whether an operator is approved for all tickets of an owner
+pub fn is_operator(env: &Env, owner: &Address, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Operator(owner.clone(), operator.clone()))
+}
+
+/// Store the pending transfer request of a ticket
+pub fn set_transfer_request(env: &Env, request: &TransferRequest) {
+    let key = DataKey::TransferRequest(request.ticket_id);
+    env.storage().persistent().set(&key, request);
+    extend_persistent(env, &key);
+}
+
+/// Store the escrow signer set of an event
+pub fn set_escrow_signers(env: &Env, event_id: u64, config: &EscrowSigners) {
+    let key = DataKey::EscrowSigners(event_id);
+    env.storage().persistent().set(&key, config);
+    extend_persistent(env, &key);
+}
+
+/// Get the escrow signer set of an event, if one was attached at publish time
+pub fn get_escrow_signers(env: &Env, event_id: u64) -> Option<EscrowSigners> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowSigners(event_id))
+}
+
+/// Store a signer's approval of an escrow movement, replacing any earlier one
+pub fn set_escrow_approval(env: &Env, event_id: u64, signer: &Address, approval: &EscrowApproval) {
+    let key = DataKey::EscrowApproval(event_id, signer.clone());
+    env.storage().persistent().set(&key, approval);
+    extend_persistent(env, &key);
+}
+
+/// Get a signer's approval of an escrow movement
+pub fn get_escrow_approval(env: &Env, event_id: u64, signer: &Address) -> Option<EscrowApproval> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowApproval(event_id, signer.clone()))
+}
+
+/// Remove a signer's approval of an escrow movement
+pub fn remove_escrow_approval(env: &Env, event_id: u64, signer: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EscrowApproval(event_id, signer.clone()));
+}
+
+/// Remove the approvals of every signer of an event
+pub fn clear_escrow_approvals(env: &Env, event_id: u64, config: &EscrowSigners) {
+    for signer in config.signers.iter() {
+        remove_escrow_approval(env, event_id, &signer);
+    }
+}
+
+/// Get the pending transfer request of a ticket
+pub fn get_transfer_request(env: &Env, ticket_id: u64) -> Result<TransferRequest, LumentixError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TransferRequest(ticket_id))
+        .ok_or(LumentixError::TransferRequestNotFound)
+}
+
+/// Check whether a ticket has a pending transfer request
+pub fn has_transfer_request(env: &Env, ticket_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::TransferRequest(ticket_id))
+}
+
+/// Remove the pending transfer request of a ticket
+pub fn remove_transfer_request(env: &Env, ticket_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::TransferRequest(ticket_id));
+}
+
+/// Store an unclaimed gift under the hash of its claim code
+pub fn set_gift(env: &Env, gift: &Gift) {
+    let key = DataKey::Gift(gift.code_hash.clone());
+    env.storage().persistent().set(&key, gift);
+    extend_persistent(env, &key);
+}
+
+/// Get an unclaimed gift by the hash of its claim code
+pub fn get_gift(env: &Env, code_hash: &BytesN<32>) -> Result<Gift, LumentixError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Gift(code_hash.clone()))
+        .ok_or(LumentixError::GiftNotFound)
+}
+
+/// Check whether an unclaimed gift exists for a claim code hash
+pub fn has_gift(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Gift(code_hash.clone()))
+}
+
+/// Remove a gift once claimed or reclaimed
+pub fn remove_gift(env: &Env, code_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Gift(code_hash.clone()));
+}
+
+/// Record the ledger in which a gift claim commitment was made. Commitments live in
+/// temporary storage and lapse once the reveal window has passed
+pub fn set_gift_claim(env: &Env, commitment: &BytesN<32>, ledger: u32) {
+    let key = DataKey::GiftClaim(commitment.clone());
+    env.storage().temporary().set(&key, &ledger);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GIFT_CLAIM_WINDOW, GIFT_CLAIM_WINDOW);
+}
+
+/// Get the ledger in which a gift claim commitment was made
+pub fn get_gift_claim(env: &Env, commitment: &BytesN<32>) -> Option<u32> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::GiftClaim(commitment.clone()))
+}
+
+/// Remove a gift claim commitment once it has been revealed
+pub fn remove_gift_claim(env: &Env, commitment: &BytesN<32>) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::GiftClaim(commitment.clone()));
+}
+
+/// Add an event to the category, tag and start day discovery indexes
+pub fn index_event(env: &Env, event: &Event) {
+    add_to_index(env, &EventIndex::Category(event.category), event.id);
+    for tag in event.tags.iter() {
+        add_to_index(env, &EventIndex::Tag(tag), event.id);
+    }
+    let day = event.start_time / SECONDS_PER_DAY;
+    add_to_index(env, &EventIndex::StartDay(day), event.id);
+}
+
+/// Remove an event from the category, tag and start day discovery indexes
+pub fn unindex_event(env: &Env, event: &Event) {
+    remove_from_index(env, &EventIndex::Category(event.category), event.id);
+    for tag in event.tags.iter() {
+        remove_from_index(env, &EventIndex::Tag(tag), event.id);
+    }
+    let day = event.start_time / SECONDS_PER_DAY;
+    remove_from_index(env, &EventIndex::StartDay(day), event.id);
+}
+
+/// Add an event to a discovery index. Each member is its own entry, kept densely
+/// packed by slot, so adding or removing an event never rewrites a growing list.
+pub fn add_to_index(env: &Env, index: &EventIndex, event_id: u64) {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::IndexMember(index.clone(), event_id))
+    {
+        return;
+    }
+
+    let size = get_index_size(env, index);
+    set_index_entry(env, &DataKey::IndexSlot(index.clone(), size), &event_id);
+    set_index_entry(env, &DataKey::IndexMember(index.clone(), event_id), &size);
+    set_index_entry(env, &DataKey::IndexSize(index.clone()), &(size + 1));
+}
+
+/// Remove an event from a discovery index, moving the last member into its slot
+pub fn remove_from_index(env: &Env, index: &EventIndex, event_id: u64) {
+    let member_key = DataKey::IndexMember(index.clone(), event_id);
+    let slot: u32 = match env.storage().persistent().get(&member_key) {
+        Some(slot) => slot,
+        None => return,
+    };
+
+    let last = get_index_size(env, index) - 1;
+    let last_key = DataKey::IndexSlot(index.clone(), last);
+    if slot != last {
+        let moved: u64 = env.storage().persistent().get(&last_key).unwrap();
+        set_index_entry(env, &DataKey::IndexSlot(index.clone(), slot), &moved);
+        set_index_entry(env, &DataKey::IndexMember(index.clone(), moved), &slot);
+    }
+    env.storage().persistent().remove(&last_key);
+    env.storage().persistent().remove(&member_key);
+    set_index_entry(env, &DataKey::IndexSize(index.clone()), &last);
+}
+
+/// Get the number of events in a discovery index
+pub fn get_index_size(env: &Env, index: &EventIndex) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexSize(index.clone()))
+        .unwrap_or(0)
+}
+
+/// Get up to `limit` event ids of a discovery index, starting at slot `start`
+pub fn get_index_page(env: &Env, index: &EventIndex, start: u32, limit: u32) -> Vec<u64> {
+    let end = get_index_size(env, index).min(start.saturating_add(limit));
+    let mut event_ids = Vec::new(env);
+    for slot in start..end {
+        if let Some(event_id) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::IndexSlot(index.clone(), slot))
+        {
+            event_ids.push_back(event_id);
+        }
+    }
+    event_ids
+}
+
+fn set_index_entry<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+/// Get next series ID
+pub fn get_next_series_id(env: &Env) -> u64 {
+    get_counter(env, &DataKey::SeriesCounter).unwrap_or(1)
+}
+
+/// Increment series ID counter
+pub fn increment_series_id(env: &Env) {
+    let next_id = get_next_series_id(env) + 1;
+    set_counter(env, &DataKey::SeriesCounter, &next_id);
+}
+
+/// Set event series data
+pub fn set_series(env: &Env, series_id: u64, series: &EventSeries) {
+    let key = DataKey::Series(series_id);
+    env.storage().persistent().set(&key, series);
+    extend_persistent(env, &key);
+}
+
+/// Get event series data
+pub fn get_series(env: &Env, series_id: u64) -> Result<EventSeries, LumentixError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Series(series_id))
+        .ok_or(LumentixError::SeriesNotFound)
+}
+
+/// Remove an event together with its escrow, session, signer and ticket index records
+pub fn remove_event(env: &Env, event_id: u64) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::Event(event_id));
+    storage.remove(&DataKey::Escrow(event_id));
+    storage.remove(&DataKey::Sessions(event_id));
+    storage.remove(&DataKey::KeepAlive(event_id));
+    storage.remove(&DataKey::KeepAliveCursor(event_id));
+    storage.remove(&DataKey::ValidatorCount(event_id));
+    storage.remove(&DataKey::BundleRefCount(event_id));
+    for index in 0..get_event_ticket_count(env, event_id) {
+        storage.remove(&DataKey::EventTicket(event_id, index));
+    }
+    storage.remove(&DataKey::EventTicketCount(event_id));
+    if let Some(config) = get_escrow_signers(env, event_id) {
+        clear_escrow_approvals(env, event_id, &config);
+        storage.remove(&DataKey::EscrowSigners(event_id));
+    }
+
+    let removed = get_removed_event_count(env) + 1;
+    set_counter(env, &DataKey::RemovedEvents, &removed);
+}
+
+/// Get the number of deleted or archived events
+pub fn get_removed_event_count(env: &Env) -> u64 {
+    get_counter(env, &DataKey::RemovedEvents).unwrap_or(0)
+}
+
+/// Remove a ticket together with its per-ticket admission, pass and transfer records
+pub fn remove_ticket(env: &Env, ticket_id: u64, sessions: &Vec<Session>) {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::Ticket(ticket_id));
+    storage.remove(&DataKey::Admission(ticket_id, NO_SESSION));
+    for session in sessions.iter() {
+        storage.remove(&DataKey::Admission(ticket_id, session.id));
+    }
+    storage.remove(&DataKey::EarlyEntry(ticket_id));
+    storage.remove(&DataKey::PassKey(ticket_id));
+    storage.remove(&DataKey::LastScan(ticket_id));
+    storage.remove(&DataKey::Approval(ticket_id));
+    storage.remove(&DataKey::TransferRequest(ticket_id));
+}
+
+/// Store the compact summary kept for an archived event
+pub fn set_archived_event(env: &Env, summary: &EventSummary) {
+    let key = DataKey::Archive(summary.id);
+    env.storage().persistent().set(&key, summary);
+    extend_persistent(env, &key);
+}
+
+/// Get the compact summary of an archived event
+pub fn get_archived_event(env: &Env, event_id: u64) -> Result<EventSummary, LumentixError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Archive(event_id))
+        .ok_or(LumentixError::EventNotFound)
+}
+
+/// Extend the TTL of an event record together with its escrow and sessions
+pub fn extend_event_ttl(env: &Env, event_id: u64, ledgers: u32) {
+    let storage = env.storage().persistent();
+    extend_persistent_for(env, &DataKey::Event(event_id), ledgers);
+    for key in [
+        DataKey::Escrow(event_id),
+        DataKey::Sessions(event_id),
+        DataKey::EscrowSigners(event_id),
+    ] {
+        if storage.has(&key) {
+            extend_persistent_for(env, &key, ledgers);
+        }
+    }
+}
+
+/// Extend the TTL of a ticket record
+pub fn extend_ticket_ttl(env: &Env, ticket_id: u64, ledgers: u32) {
+    extend_persistent_for(env, &DataKey::Ticket(ticket_id), ledgers);
+}
+
+/// Extend the TTL of a page of an event's ticket index together with the tickets it
+/// lists. Returns the index slot following the page
+pub fn extend_event_tickets_ttl(
+    env: &Env,
+    event_id: u64,
+    start: u32,
+    limit: u32,
+    ledgers: u32,
+) -> u32 {
+    let storage = env.storage().persistent();
+    let count = get_event_ticket_count(env, event_id);
+    if count > 0 {
+        extend_persistent_for(env, &DataKey::EventTicketCount(event_id), ledgers);
+    }
+
+    let end = start.saturating_add(limit).min(count);
+    for index in start..end {
+        let slot = DataKey::EventTicket(event_id, index);
+        let Some(ticket_id) = storage.get::<_, u64>(&slot) else {
+            continue;
+        };
+        extend_persistent_for(env, &slot, ledgers);
+        if storage.has(&DataKey::Ticket(ticket_id)) {
+            extend_ticket_ttl(env, ticket_id, ledgers);
+        }
+    }
+    end.max(start)
+}
+
+/// Record the ledger until which an event's records are guaranteed to stay live
+pub fn set_keep_alive_ledger(env: &Env, event_id: u64, live_until: u32) {
+    let key = DataKey::KeepAlive(event_id);
+    let ledgers = live_until.saturating_sub(env.ledger().sequence());
+    env.storage().persistent().set(&key, &live_until);
+    extend_persistent_for(env, &key, ledgers);
+}
+
+/// Get the ledger until which an event's records are guaranteed to stay live
+pub fn get_keep_alive_ledger(env: &Env, event_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KeepAlive(event_id))
+}
+
+/// Record an unfinished keeper run as the next ticket index slot and the ledger
+/// the run guarantees
+pub fn set_keep_alive_cursor(env: &Env, event_id: u64, next_index: u32, live_until: u32) {
+    let key = DataKey::KeepAliveCursor(event_id);
+    env.storage()
+        .persistent()
+        .set(&key, &(next_index, live_until));
+    extend_persistent(env, &key);
+}
+
+/// Get the next ticket index slot and guaranteed ledger of an unfinished keeper run
+pub fn get_keep_alive_cursor(env: &Env, event_id: u64) -> Option<(u32, u32)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KeepAliveCursor(event_id))
+}
+
+/// Clear the cursor of a finished keeper run
+pub fn remove_keep_alive_cursor(env: &Env, event_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::KeepAliveCursor(event_id));
 }
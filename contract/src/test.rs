@@ -1,12 +1,20 @@
 #![allow(irrefutable_let_patterns)]
 
+use crate::admission;
 use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
-use crate::storage;
-use crate::types::{EventStatus, Ticket};
-use soroban_sdk::xdr;
+use crate::migration::{LegacyEvent, LegacyTicket};
+use crate::storage::{self, DataKey};
+use crate::types::{
+    CheckInResult, CheckInWindow, EscrowAction, EventCategory, EventStatus, MigrationPhase,
+    Recurrence, RefundPolicy, SeriesTemplate, SignedScan, Ticket, TransferPolicy,
+    DEFAULT_CHECKIN_WINDOW, DISPUTE_BUFFER, GIFT_CLAIM_WINDOW, INSTANCE_LIFETIME,
+    PERSISTENT_LIFETIME, SECONDS_PER_DAY, SECONDS_PER_LEDGER, TEMPORARY_LIFETIME,
+};
+use soroban_sdk::xdr::{self, ToXdr};
 use soroban_sdk::{
-    testutils::Address as _, testutils::Events, testutils::Ledger, Address, Env, String,
+    testutils::Address as _, testutils::Events, testutils::Ledger, vec, Address, Bytes, BytesN,
+    Env, String, Symbol, Vec,
 };
 
 fn create_test_contract(env: &Env) -> (Address, LumentixContractClient<'_>) {
@@ -43,6 +51,8 @@ fn create_and_publish_event(
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Publish the event
@@ -101,6 +111,8 @@ fn test_create_event_success() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     assert_eq!(event_id, 1);
@@ -127,6 +139,8 @@ fn test_create_event_invalid_price() {
         &2000u64,
         &0i128, // Invalid price
         &50u32,
+        &None,
+        &None,
     );
 
     assert_eq!(result, Err(Ok(LumentixError::InvalidAmount)));
@@ -149,6 +163,8 @@ fn test_create_event_invalid_capacity() {
         &2000u64,
         &100i128,
         &0u32, // Invalid capacity
+        &None,
+        &None,
     );
 
     assert_eq!(result, Err(Ok(LumentixError::CapacityExceeded)));
@@ -171,6 +187,8 @@ fn test_create_event_invalid_time_range() {
         &1000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     assert_eq!(result, Err(Ok(LumentixError::InvalidTimeRange)));
@@ -193,6 +211,8 @@ fn test_create_event_empty_name() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     assert_eq!(result, Err(Ok(LumentixError::EmptyString)));
@@ -249,6 +269,8 @@ fn test_purchase_ticket_sold_out() {
         &2000u64,
         &100i128,
         &1u32,
+        &None,
+        &None,
     );
 
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
@@ -279,6 +301,8 @@ fn test_purchase_ticket_draft_status_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to purchase ticket for draft event
@@ -373,6 +397,8 @@ fn test_batch_purchase_tickets_rejects_when_capacity_exceeded() {
         &2000u64,
         &100i128,
         &2u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
@@ -395,7 +421,7 @@ fn test_batch_purchase_tickets_rejects_invalid_payment_amount() {
     assert_eq!(underpayment, Err(Ok(LumentixError::InsufficientFunds)));
 
     let overpayment = client.try_batch_purchase_tickets(&buyer, &event_id, &2u32, &250i128);
-    assert_eq!(overpayment, Err(Ok(LumentixError::InsufficientFunds)));
+    assert_eq!(overpayment, Err(Ok(LumentixError::Overpayment)));
 }
 
 // ============================================================================
@@ -545,6 +571,8 @@ fn test_full_event_cancellation_with_multiple_buyer_refunds() {
         &2000u64,
         &100i128,
         &4u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
@@ -566,7 +594,8 @@ fn test_full_event_cancellation_with_multiple_buyer_refunds() {
     let used_refund = client.try_refund_ticket(&ticket_id_1, &buyer1);
     assert_eq!(used_refund, Err(Ok(LumentixError::TicketAlreadyUsed)));
 
-    assert_eq!(client.get_escrow_balance(&event_id), 80i128);
+    // Only the used ticket's 95 escrow share remains
+    assert_eq!(client.get_escrow_balance(&event_id), 95i128);
 
     let ticket1 = client.get_ticket_info(&ticket_id_1);
     let ticket2 = client.get_ticket_info(&ticket_id_2);
@@ -673,6 +702,8 @@ fn test_get_event() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let event = client.get_event(&event_id);
@@ -701,6 +732,8 @@ fn test_get_total_events() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     assert_eq!(client.get_total_events(), 1);
@@ -715,6 +748,8 @@ fn test_get_total_events() {
         &4000u64,
         &200i128,
         &100u32,
+        &None,
+        &None,
     );
 
     assert_eq!(client.get_total_events(), 2);
@@ -748,6 +783,8 @@ fn test_update_status_draft_to_published() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let result = client.try_update_event_status(&event_id, &EventStatus::Published, &organizer);
@@ -791,6 +828,8 @@ fn test_update_status_invalid_transition() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to go directly from Draft to Completed
@@ -1056,7 +1095,19 @@ fn test_platform_fee_calculation_precision() {
     // Set platform fee to 2.5% (250 basis points)
     client.set_platform_fee(&admin, &250u32);
 
-    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Premium Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &1000i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
     // Purchase ticket for 1000
     client.purchase_ticket(&buyer, &event_id, &1000i128);
@@ -1079,8 +1130,32 @@ fn test_multiple_events_platform_fee_accumulation() {
     // Set platform fee to 5% (500 basis points)
     client.set_platform_fee(&admin, &500u32);
 
-    let event_id_1 = create_and_publish_event(&env, &client, &organizer1);
-    let event_id_2 = create_and_publish_event(&env, &client, &organizer2);
+    let event_id_1 = client.create_event(
+        &organizer1,
+        &String::from_str(&env, "Event 1"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &200i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id_1, &EventStatus::Published, &organizer1);
+    let event_id_2 = client.create_event(
+        &organizer2,
+        &String::from_str(&env, "Event 2"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &300i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id_2, &EventStatus::Published, &organizer2);
 
     // Purchase tickets from both events
     client.purchase_ticket(&buyer, &event_id_1, &200i128); // Fee: 10
@@ -1252,6 +1327,8 @@ fn test_status_transition_draft_to_completed_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to go directly from Draft to Completed
@@ -1276,6 +1353,8 @@ fn test_status_transition_draft_to_cancelled_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to cancel draft event via update_event_status
@@ -1395,6 +1474,8 @@ fn test_event_capacity_enforcement() {
         &2000u64,
         &100i128,
         &2u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
@@ -1428,6 +1509,8 @@ fn test_get_availability() {
         &2000u64,
         &100i128,
         &5u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
@@ -1465,6 +1548,8 @@ fn test_refund_frees_capacity() {
         &2000u64,
         &100i128,
         &2u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
@@ -1504,6 +1589,8 @@ fn test_event_id_increments() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let event_id_2 = client.create_event(
@@ -1515,6 +1602,8 @@ fn test_event_id_increments() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     assert_eq!(event_id_1, 1);
@@ -1639,6 +1728,8 @@ fn test_full_event_lifecycle_happy_path() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
     assert_eq!(client.get_event(&event_id).status, EventStatus::Draft);
 
@@ -1755,6 +1846,8 @@ fn test_get_active_events_only_published() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id_1, &EventStatus::Published, &organizer);
 
@@ -1768,6 +1861,8 @@ fn test_get_active_events_only_published() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Event 3: Published
@@ -1780,6 +1875,8 @@ fn test_get_active_events_only_published() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id_3, &EventStatus::Published, &organizer);
 
@@ -1814,6 +1911,8 @@ fn test_get_active_events_empty() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let active_events = client.get_active_events();
@@ -1849,6 +1948,8 @@ fn test_get_events_by_organizer_single_event_full_field_validation() {
         &5678u64,
         &250i128,
         &75u32,
+        &None,
+        &None,
     );
 
     let events = client.get_events_by_organizer(&organizer);
@@ -1888,6 +1989,8 @@ fn test_get_events_by_organizer_multiple_events() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let event_id_2 = client.create_event(
@@ -1899,6 +2002,8 @@ fn test_get_events_by_organizer_multiple_events() {
         &4000u64,
         &200i128,
         &25u32,
+        &None,
+        &None,
     );
 
     let events = client.get_events_by_organizer(&organizer);
@@ -1925,6 +2030,8 @@ fn test_get_events_by_organizer_isolates_other_organizers() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let event_id_b1 = client.create_event(
@@ -1936,6 +2043,8 @@ fn test_get_events_by_organizer_isolates_other_organizers() {
         &4000u64,
         &150i128,
         &40u32,
+        &None,
+        &None,
     );
 
     let event_id_a2 = client.create_event(
@@ -1947,6 +2056,8 @@ fn test_get_events_by_organizer_isolates_other_organizers() {
         &6000u64,
         &200i128,
         &30u32,
+        &None,
+        &None,
     );
 
     let organizer_a_events = client.get_events_by_organizer(&organizer_a);
@@ -1976,6 +2087,8 @@ fn test_get_events_by_organizer_includes_cancelled_events() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let cancelled_event_id = create_and_publish_event(&env, &client, &organizer);
@@ -1988,6 +2101,28 @@ fn test_get_events_by_organizer_includes_cancelled_events() {
     assert_eq!(events.get(1).unwrap().status, EventStatus::Cancelled);
 }
 
+#[test]
+#[allow(deprecated)]
+fn test_get_events_by_organizer_and_status_alias() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+
+    let published_id = create_and_publish_event(&env, &client, &organizer);
+    let cancelled_id = create_and_publish_event(&env, &client, &organizer);
+    client.cancel_event(&organizer, &cancelled_id);
+
+    let events = client.get_events_by_organizer_and_status(&organizer, &EventStatus::Published);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events.get(0).unwrap().id, published_id);
+    assert_eq!(
+        events,
+        client.get_events_by_org_status(&organizer, &EventStatus::Published)
+    );
+}
+
 // ============================================================================
 // EVENT EMISSION TESTS
 // ============================================================================
@@ -2009,6 +2144,8 @@ fn test_event_created_emitted_with_correct_fields() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Get all events emitted
@@ -2090,8 +2227,8 @@ fn test_ticket_purchased_with_platform_fee_emitted_correctly() {
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    // Purchase ticket for 200
-    client.purchase_ticket(&buyer, &event_id, &200i128);
+    // Purchase ticket for 100
+    client.purchase_ticket(&buyer, &event_id, &100i128);
 
     // Find TicketPurchased event
     let events = env.events().all();
@@ -2344,6 +2481,8 @@ fn test_event_status_changed_emitted_with_correct_statuses() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Update status from Draft to Published
@@ -2644,6 +2783,8 @@ fn test_update_event_draft_success_all_fields() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Update all fields
@@ -2657,6 +2798,8 @@ fn test_update_event_draft_success_all_fields() {
         &2500u64,
         &150i128,
         &100u32,
+        &None,
+        &None,
     );
     assert!(result.is_ok());
 }
@@ -2683,6 +2826,8 @@ fn test_update_event_published_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
 }
@@ -2710,6 +2855,8 @@ fn test_update_event_cancelled_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
 }
@@ -2738,6 +2885,8 @@ fn test_update_event_completed_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
 }
@@ -2761,6 +2910,8 @@ fn test_update_event_unauthorized_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to update as unauthorized user
@@ -2774,6 +2925,8 @@ fn test_update_event_unauthorized_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
 }
@@ -2796,6 +2949,8 @@ fn test_update_event_invalid_time_range_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to update with invalid time range (start >= end)
@@ -2809,6 +2964,8 @@ fn test_update_event_invalid_time_range_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::InvalidTimeRange)));
 }
@@ -2831,6 +2988,8 @@ fn test_update_event_empty_name_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to update with empty name
@@ -2844,6 +3003,8 @@ fn test_update_event_empty_name_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::EmptyString)));
 }
@@ -2866,6 +3027,8 @@ fn test_update_event_zero_ticket_price_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Try to update with zero ticket price
@@ -2879,6 +3042,8 @@ fn test_update_event_zero_ticket_price_fails() {
         &2000u64,
         &0i128, // Zero price
         &50u32,
+        &None,
+        &None,
     );
     assert_eq!(result, Err(Ok(LumentixError::InvalidAmount)));
 }
@@ -2902,6 +3067,8 @@ fn test_update_event_reduce_max_tickets_below_sold_fails() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     // Publish and sell 5 tickets
@@ -2925,6 +3092,8 @@ fn test_update_event_reduce_max_tickets_below_sold_fails() {
         &2000u64,
         &100i128,
         &3u32, // Trying to reduce below tickets_sold (5)
+        &None,
+        &None,
     );
     // The error is InvalidStatusTransition because Published events can't be updated
     // The capacity check (max_tickets < tickets_sold) would only apply to Draft events
@@ -2949,6 +3118,8 @@ fn test_update_event_increase_max_tickets_succeeds() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Increase max_tickets to 200
@@ -2962,6 +3133,8 @@ fn test_update_event_increase_max_tickets_succeeds() {
         &2000u64,
         &100i128,
         &200u32,
+        &None,
+        &None,
     );
     assert!(result.is_ok());
 
@@ -2988,6 +3161,8 @@ fn test_update_event_get_event_returns_updated_values() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     // Update all fields
@@ -3001,6 +3176,8 @@ fn test_update_event_get_event_returns_updated_values() {
         &2500u64,
         &150i128,
         &100u32,
+        &None,
+        &None,
     );
 
     // Verify get_event returns updated values
@@ -3092,7 +3269,10 @@ fn test_escrow_balance_zero_before_any_tickets_sold() {
 
     // Escrow balance should be 0
     let escrow_balance = client.get_escrow_balance(&event_id);
-    assert_eq!(escrow_balance, 0i128, "Escrow balance should be 0 before any tickets are sold");
+    assert_eq!(
+        escrow_balance, 0i128,
+        "Escrow balance should be 0 before any tickets are sold"
+    );
 }
 
 #[test]
@@ -3276,7 +3456,10 @@ fn test_escrow_balance_with_ten_percent_platform_fee() {
 
     // Verify platform balance is 40
     let platform_balance = client.get_platform_balance();
-    assert_eq!(platform_balance, 40i128, "Platform should collect 40 in fees");
+    assert_eq!(
+        platform_balance, 40i128,
+        "Platform should collect 40 in fees"
+    );
 }
 
 #[test]
@@ -3304,6 +3487,8 @@ fn test_escrow_balance_multiple_events_independent() {
         &4000u64,
         &200i128,
         &50u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id_2, &EventStatus::Published, &organizer);
 
@@ -3330,15 +3515,14 @@ fn test_escrow_balance_multiple_events_independent() {
     );
 
     // Total escrow across both events
-    assert_eq!(
-        escrow_1 + escrow_2,
-        665i128,
-        "Total escrow should be 665"
-    );
+    assert_eq!(escrow_1 + escrow_2, 665i128, "Total escrow should be 665");
 
     // Verify platform collected total fees: 15 + 20 = 35
     let platform_balance = client.get_platform_balance();
-    assert_eq!(platform_balance, 35i128, "Platform should collect 35 total fees");
+    assert_eq!(
+        platform_balance, 35i128,
+        "Platform should collect 35 total fees"
+    );
 }
 
 #[test]
@@ -3359,6 +3543,8 @@ fn test_get_ticket_validity_false_for_ticket_on_draft_event() {
         &2000u64,
         &100i128,
         &10u32,
+        &None,
+        &None,
     );
 
     let ticket_id = env.as_contract(&contract_id, || {
@@ -3374,6 +3560,10 @@ fn test_get_ticket_validity_false_for_ticket_on_draft_event() {
                 purchase_time: env.ledger().timestamp(),
                 used: false,
                 refunded: false,
+                paid_amount: 100,
+                platform_fee: 0,
+                escrow_amount: 100,
+                sessions: Vec::new(&env),
             },
         );
         ticket_id
@@ -3528,6 +3718,8 @@ fn test_transfer_ticket_draft_event_fails() {
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
 
     let ticket_id = env.as_contract(&contract_id, || {
@@ -3543,6 +3735,10 @@ fn test_transfer_ticket_draft_event_fails() {
                 purchase_time: env.ledger().timestamp(),
                 used: false,
                 refunded: false,
+                paid_amount: 100,
+                platform_fee: 0,
+                escrow_amount: 100,
+                sessions: Vec::new(&env),
             },
         );
         ticket_id
@@ -3744,6 +3940,8 @@ fn test_get_tickets_by_buyer_returns_multiple_tickets_across_events() {
         &4000u64,
         &150i128,
         &30u32,
+        &None,
+        &None,
     );
     client.update_event_status(&second_event, &EventStatus::Published, &organizer);
 
@@ -3847,7 +4045,7 @@ fn test_withdraw_funds_success() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Create and publish event
@@ -3861,14 +4059,10 @@ fn test_withdraw_funds_success() {
     // Withdraw funds
     let withdraw_amount = 200i128;
     let new_balance = client.withdraw_funds(&organizer, &event_id, &withdraw_amount);
-    
+
     // Verify balance updated correctly
     assert_eq!(new_balance, 300i128);
     assert_eq!(client.get_escrow_balance(&event_id), 300i128);
-
-    // Verify withdrawal event was emitted
-    let events = env.events().all();
-    // Events are emitted, we can't easily count them but the test passing confirms proper functionality
 }
 
 #[test]
@@ -3889,19 +4083,19 @@ fn test_withdraw_funds_by_admin() {
     // Admin withdraws funds
     let withdraw_amount = 200i128;
     let new_balance = client.withdraw_funds(&admin, &event_id, &withdraw_amount);
-    
+
     // Verify balance updated correctly
     assert_eq!(new_balance, 300i128);
     assert_eq!(client.get_escrow_balance(&event_id), 300i128);
 }
 
 #[test]
-#[should_panic(expected = "Unauthorized")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_withdraw_funds_unauthorized() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
     let unauthorized_user = Address::generate(&env);
 
@@ -3917,12 +4111,12 @@ fn test_withdraw_funds_unauthorized() {
 }
 
 #[test]
-#[should_panic(expected = "InvalidAmount")]
+#[should_panic(expected = "Error(Contract, #13)")]
 fn test_withdraw_funds_zero_amount() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Create and publish event
@@ -3937,12 +4131,12 @@ fn test_withdraw_funds_zero_amount() {
 }
 
 #[test]
-#[should_panic(expected = "InvalidAmount")]
+#[should_panic(expected = "Error(Contract, #13)")]
 fn test_withdraw_funds_negative_amount() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Create and publish event
@@ -3957,12 +4151,12 @@ fn test_withdraw_funds_negative_amount() {
 }
 
 #[test]
-#[should_panic(expected = "InsufficientEscrow")]
+#[should_panic(expected = "Error(Contract, #18)")]
 fn test_withdraw_funds_insufficient_balance() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Create and publish event
@@ -3977,12 +4171,12 @@ fn test_withdraw_funds_insufficient_balance() {
 }
 
 #[test]
-#[should_panic(expected = "InvalidStatusTransition")]
+#[should_panic(expected = "Error(Contract, #8)")]
 fn test_withdraw_funds_cancelled_event() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Create and publish event
@@ -3996,12 +4190,12 @@ fn test_withdraw_funds_cancelled_event() {
 }
 
 #[test]
-#[should_panic(expected = "EventNotFound")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdraw_funds_nonexistent_event() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Try to withdraw from non-existent event
@@ -4013,7 +4207,7 @@ fn test_withdraw_all_funds() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
 
     // Create and publish event
@@ -4025,7 +4219,7 @@ fn test_withdraw_all_funds() {
 
     // Withdraw all funds
     let new_balance = client.withdraw_funds(&organizer, &event_id, &deposit_amount);
-    
+
     // Verify balance is zero
     assert_eq!(new_balance, 0i128);
     assert_eq!(client.get_escrow_balance(&event_id), 0i128);
@@ -4059,3 +4253,3503 @@ fn test_multiple_withdrawals() {
     // Final balance should be zero
     assert_eq!(client.get_escrow_balance(&event_id), 0i128);
 }
+
+// ============================================================================
+// FEE ACCOUNTING INVARIANT TESTS
+// ============================================================================
+
+fn i128_from_scval(val: &xdr::ScVal) -> i128 {
+    if let xdr::ScVal::I128(parts) = val {
+        ((parts.hi as i128) << 64) | parts.lo as i128
+    } else {
+        panic!("Expected I128 value");
+    }
+}
+
+/// Sum the (platform_fee, organizer_amount) fields of the TicketPurchased events from the last invocation.
+fn sum_ticket_purchased_shares(env: &Env) -> (i128, i128) {
+    let mut fee_total = 0i128;
+    let mut escrow_total = 0i128;
+    for xdr_event in env.events().all().events() {
+        if let xdr::ContractEventBody::V0(body) = &xdr_event.body {
+            if let xdr::ScVal::Symbol(topic_sym) = &body.topics[0] {
+                if topic_sym.as_slice() == b"tktbuy" {
+                    if let xdr::ScVal::Vec(Some(data_vec)) = &body.data {
+                        fee_total += i128_from_scval(&data_vec[4]);
+                        escrow_total += i128_from_scval(&data_vec[5]);
+                    }
+                }
+            }
+        }
+    }
+    (fee_total, escrow_total)
+}
+
+#[test]
+fn test_batch_purchase_event_shares_sum_to_stored_balances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    // 3.33% on 7 x 37 = 259 -> fee 8, which does not divide evenly across 7 tickets
+    client.set_platform_fee(&admin, &333u32);
+
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Odd Price Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &37i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+
+    client.batch_purchase_tickets(&buyer, &event_id, &7u32, &259i128);
+
+    let (fee_total, escrow_total) = sum_ticket_purchased_shares(&env);
+    assert_eq!(client.get_platform_balance(), 8i128);
+    assert_eq!(client.get_escrow_balance(&event_id), 251i128);
+    assert_eq!(fee_total, client.get_platform_balance());
+    assert_eq!(escrow_total, client.get_escrow_balance(&event_id));
+}
+
+#[test]
+fn test_mixed_purchases_event_shares_sum_to_stored_balances() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Odd Price Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &99i128,
+        &100u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+
+    // Events only cover the latest invocation, so accumulate after every purchase
+    let mut fee_total = 0i128;
+    let mut escrow_total = 0i128;
+    for (fee_bps, quantity) in [(250u32, 3u32), (777, 9), (1, 10), (9999, 4)] {
+        client.set_platform_fee(&admin, &fee_bps);
+
+        client.purchase_ticket(&buyer, &event_id, &99i128);
+        let (fee, escrow) = sum_ticket_purchased_shares(&env);
+        fee_total += fee;
+        escrow_total += escrow;
+
+        client.batch_purchase_tickets(&buyer, &event_id, &quantity, &(99i128 * quantity as i128));
+        let (fee, escrow) = sum_ticket_purchased_shares(&env);
+        fee_total += fee;
+        escrow_total += escrow;
+    }
+
+    assert_eq!(fee_total, client.get_platform_balance());
+    assert_eq!(escrow_total, client.get_escrow_balance(&event_id));
+    assert_eq!(
+        fee_total + escrow_total,
+        client.get_event_revenue(&event_id)
+    );
+}
+
+#[test]
+fn test_purchase_ticket_rejects_overpayment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let result = client.try_purchase_ticket(&buyer, &event_id, &150i128);
+    assert_eq!(result, Err(Ok(LumentixError::Overpayment)));
+
+    // Nothing was collected or sold
+    assert_eq!(client.get_platform_balance(), 0i128);
+    assert_eq!(client.get_escrow_balance(&event_id), 0i128);
+    assert_eq!(client.get_event(&event_id).tickets_sold, 0);
+}
+
+// ============================================================================
+// REFUND ACCOUNTING TESTS
+// ============================================================================
+
+#[test]
+fn test_ticket_records_paid_amount_fee_and_escrow_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_platform_fee(&admin, &250u32);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket = client.get_ticket_info(&ticket_id);
+    assert_eq!(ticket.paid_amount, 100);
+    assert_eq!(ticket.platform_fee, 2);
+    assert_eq!(ticket.escrow_amount, 98);
+
+    // 3 x 100 at 2.5% -> fee 7, dust of 1 goes to the first ticket
+    let batch = client.batch_purchase_tickets(&buyer, &event_id, &3u32, &300i128);
+    let fees: [i128; 3] = [3, 2, 2];
+    for (i, ticket_id) in batch.iter().enumerate() {
+        let ticket = client.get_ticket_info(&ticket_id);
+        assert_eq!(ticket.paid_amount, 100);
+        assert_eq!(ticket.platform_fee, fees[i]);
+        assert_eq!(ticket.escrow_amount, 100 - fees[i]);
+    }
+}
+
+#[test]
+fn test_refund_uses_recorded_escrow_share_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_platform_fee(&admin, &1000u32);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.cancel_event(&organizer, &event_id);
+
+    assert_eq!(client.get_refund_policy(), RefundPolicy::EscrowOnly);
+    client.refund_ticket(&ticket_id, &buyer);
+
+    // Escrow fully drained, platform keeps its fee
+    assert_eq!(client.get_escrow_balance(&event_id), 0i128);
+    assert_eq!(client.get_platform_balance(), 10i128);
+}
+
+/// Refund amount and fee shortfall of the last TicketRefunded event
+fn refunded_amounts(env: &Env) -> Option<(i128, i128)> {
+    let mut amounts = None;
+    for xdr_event in env.events().all().events() {
+        if let xdr::ContractEventBody::V0(body) = &xdr_event.body {
+            if let xdr::ScVal::Symbol(topic_sym) = &body.topics[0] {
+                if topic_sym.as_slice() == b"tktrefnd" {
+                    if let xdr::ScVal::Vec(Some(data_vec)) = &body.data {
+                        amounts =
+                            Some((i128_from_scval(&data_vec[3]), i128_from_scval(&data_vec[4])));
+                    }
+                }
+            }
+        }
+    }
+    amounts
+}
+
+#[test]
+fn test_refund_full_amount_policy_returns_platform_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_platform_fee(&admin, &1000u32);
+    client.set_refund_policy(&admin, &RefundPolicy::FullAmount);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let ticket_1 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_2 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.cancel_event(&organizer, &event_id);
+
+    client.refund_ticket(&ticket_1, &buyer);
+
+    // TicketRefunded carries the full paid amount and no shortfall
+    assert_eq!(refunded_amounts(&env), Some((100i128, 0i128)));
+
+    assert_eq!(client.get_escrow_balance(&event_id), 90i128);
+    assert_eq!(client.get_platform_balance(), 10i128);
+
+    client.refund_ticket(&ticket_2, &buyer);
+    assert_eq!(client.get_escrow_balance(&event_id), 0i128);
+    assert_eq!(client.get_platform_balance(), 0i128);
+}
+
+#[test]
+fn test_refund_full_amount_falls_back_to_escrow_when_platform_fees_withdrawn() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_platform_fee(&admin, &1000u32);
+    client.set_refund_policy(&admin, &RefundPolicy::FullAmount);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.withdraw_platform_fees(&admin);
+    client.cancel_event(&organizer, &event_id);
+
+    // The withdrawn fee cannot be returned, but the escrow share still is and the
+    // unreturned fee is reported as a shortfall
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(refunded_amounts(&env), Some((90i128, 10i128)));
+    assert!(client.get_ticket_info(&ticket_id).refunded);
+    assert_eq!(client.get_escrow_balance(&event_id), 0i128);
+    assert_eq!(client.get_platform_balance(), 0i128);
+}
+
+#[test]
+fn test_set_refund_policy_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let not_admin = Address::generate(&env);
+
+    let result = client.try_set_refund_policy(&not_admin, &RefundPolicy::FullAmount);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    assert_eq!(client.get_refund_policy(), RefundPolicy::EscrowOnly);
+}
+
+// ============================================================================
+// BUNDLE (SEASON PASS) TESTS
+// ============================================================================
+
+fn create_published_events(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+    count: u32,
+) -> Vec<u64> {
+    let mut event_ids = Vec::new(env);
+    for _ in 0..count {
+        event_ids.push_back(create_and_publish_event(env, client, organizer));
+    }
+    event_ids
+}
+
+#[test]
+fn test_purchase_bundle_mints_ticket_per_event_with_weighted_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_platform_fee(&admin, &1000u32);
+    let event_ids = create_published_events(&env, &client, &organizer, 3);
+    let weights = vec![&env, 1u32, 1, 2];
+
+    let bundle_id = client.create_bundle(
+        &organizer,
+        &String::from_str(&env, "Season Pass"),
+        &event_ids,
+        &weights,
+        &400i128,
+        &10u32,
+    );
+
+    let ticket_ids = client.purchase_bundle(&buyer, &bundle_id, &400i128);
+    assert_eq!(ticket_ids.len(), 3);
+
+    // 400 -> 40 fee, 360 escrow split 1:1:2
+    assert_eq!(client.get_platform_balance(), 40i128);
+    assert_eq!(
+        client.get_escrow_balance(&event_ids.get(0).unwrap()),
+        90i128
+    );
+    assert_eq!(
+        client.get_escrow_balance(&event_ids.get(1).unwrap()),
+        90i128
+    );
+    assert_eq!(
+        client.get_escrow_balance(&event_ids.get(2).unwrap()),
+        180i128
+    );
+
+    for (i, ticket_id) in ticket_ids.iter().enumerate() {
+        let ticket = client.get_ticket_info(&ticket_id);
+        let event_id = event_ids.get(i as u32).unwrap();
+        assert_eq!(ticket.event_id, event_id);
+        assert_eq!(ticket.owner, buyer);
+        assert_eq!(
+            ticket.paid_amount,
+            ticket.platform_fee + ticket.escrow_amount
+        );
+        assert_eq!(client.get_event(&event_id).tickets_sold, 1);
+    }
+
+    let bundle = client.get_bundle(&bundle_id);
+    assert_eq!(bundle.passes_sold, 1);
+}
+
+#[test]
+fn test_bundle_event_cancellation_refunds_only_that_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_ids = create_published_events(&env, &client, &organizer, 3);
+    let bundle_id = client.create_bundle(
+        &organizer,
+        &String::from_str(&env, "Season Pass"),
+        &event_ids,
+        &vec![&env, 1u32, 1, 1],
+        &100i128,
+        &10u32,
+    );
+
+    // 100 over 1:1:1 -> 34, 33, 33
+    let ticket_ids = client.purchase_bundle(&buyer, &bundle_id, &100i128);
+
+    let cancelled = event_ids.get(1).unwrap();
+    client.cancel_event(&organizer, &cancelled);
+    client.refund_ticket(&ticket_ids.get(1).unwrap(), &buyer);
+
+    assert_eq!(client.get_escrow_balance(&cancelled), 0i128);
+    assert_eq!(
+        client.get_escrow_balance(&event_ids.get(0).unwrap()),
+        34i128
+    );
+    assert_eq!(
+        client.get_escrow_balance(&event_ids.get(2).unwrap()),
+        33i128
+    );
+    assert!(client.get_ticket_validity(&ticket_ids.get(0).unwrap()));
+    assert!(client.get_ticket_validity(&ticket_ids.get(2).unwrap()));
+}
+
+#[test]
+fn test_purchase_bundle_enforces_capacity_and_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_ids = create_published_events(&env, &client, &organizer, 2);
+    let bundle_id = client.create_bundle(
+        &organizer,
+        &String::from_str(&env, "Two Night Pass"),
+        &event_ids,
+        &vec![&env, 1u32, 1],
+        &150i128,
+        &1u32,
+    );
+
+    let underpaid = client.try_purchase_bundle(&buyer, &bundle_id, &149i128);
+    assert_eq!(underpaid, Err(Ok(LumentixError::InsufficientFunds)));
+    let overpaid = client.try_purchase_bundle(&buyer, &bundle_id, &151i128);
+    assert_eq!(overpaid, Err(Ok(LumentixError::Overpayment)));
+
+    client.purchase_bundle(&buyer, &bundle_id, &150i128);
+    let sold_out = client.try_purchase_bundle(&buyer, &bundle_id, &150i128);
+    assert_eq!(sold_out, Err(Ok(LumentixError::EventSoldOut)));
+}
+
+#[test]
+fn test_purchase_bundle_requires_all_events_published() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_ids = create_published_events(&env, &client, &organizer, 2);
+    let bundle_id = client.create_bundle(
+        &organizer,
+        &String::from_str(&env, "Two Night Pass"),
+        &event_ids,
+        &vec![&env, 1u32, 1],
+        &150i128,
+        &5u32,
+    );
+
+    client.cancel_event(&organizer, &event_ids.get(1).unwrap());
+
+    let result = client.try_purchase_bundle(&buyer, &bundle_id, &150i128);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
+
+    // Nothing was minted on the still-published event
+    assert_eq!(client.get_event(&event_ids.get(0).unwrap()).tickets_sold, 0);
+}
+
+#[test]
+fn test_create_bundle_rejects_invalid_configuration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let other_organizer = Address::generate(&env);
+    let name = String::from_str(&env, "Pass");
+
+    let event_ids = create_published_events(&env, &client, &organizer, 2);
+    let foreign_event = create_and_publish_event(&env, &client, &other_organizer);
+
+    let mismatched =
+        client.try_create_bundle(&organizer, &name, &event_ids, &vec![&env, 1u32], &100, &5);
+    assert_eq!(mismatched, Err(Ok(LumentixError::InvalidBundle)));
+
+    let zero_weight = client.try_create_bundle(
+        &organizer,
+        &name,
+        &event_ids,
+        &vec![&env, 1u32, 0],
+        &100,
+        &5,
+    );
+    assert_eq!(zero_weight, Err(Ok(LumentixError::InvalidBundle)));
+
+    let first = event_ids.get(0).unwrap();
+    let duplicate = client.try_create_bundle(
+        &organizer,
+        &name,
+        &vec![&env, first, first],
+        &vec![&env, 1u32, 1],
+        &100,
+        &5,
+    );
+    assert_eq!(duplicate, Err(Ok(LumentixError::InvalidBundle)));
+
+    let foreign = client.try_create_bundle(
+        &organizer,
+        &name,
+        &vec![&env, first, foreign_event],
+        &vec![&env, 1u32, 1],
+        &100,
+        &5,
+    );
+    assert_eq!(foreign, Err(Ok(LumentixError::Unauthorized)));
+
+    let missing = client.try_get_bundle(&99u64);
+    assert_eq!(missing, Err(Ok(LumentixError::BundleNotFound)));
+}
+
+// ============================================================================
+// SESSION ADMISSION TESTS
+// ============================================================================
+
+/// Creates a published festival spanning 1000..4000 with three sessions.
+fn create_three_day_festival(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+) -> u64 {
+    let event_id = client.create_event(
+        organizer,
+        &String::from_str(env, "Festival"),
+        &String::from_str(env, "Three days"),
+        &String::from_str(env, "Fields"),
+        &1000u64,
+        &4000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.add_session(organizer, &event_id, &1000u64, &1999u64);
+    client.add_session(organizer, &event_id, &2000u64, &2999u64);
+    client.add_session(organizer, &event_id, &3000u64, &4000u64);
+    client.update_event_status(&event_id, &EventStatus::Published, organizer);
+    event_id
+}
+
+#[test]
+fn test_multi_session_ticket_checks_in_once_per_session() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_three_day_festival(&env, &client, &organizer);
+    assert_eq!(client.get_event_sessions(&event_id).len(), 3);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    client.use_ticket(&ticket_id, &organizer);
+    assert!(!client.get_ticket_info(&ticket_id).used);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 2500);
+    client.use_ticket(&ticket_id, &organizer);
+    assert!(!client.get_ticket_info(&ticket_id).used);
+
+    env.ledger().with_mut(|li| li.timestamp = 3500);
+    client.use_ticket(&ticket_id, &organizer);
+    assert!(client.get_ticket_info(&ticket_id).used);
+
+    for session_id in 1..=3u32 {
+        let attendees = client.get_session_attendees(&event_id, &session_id);
+        assert_eq!(attendees, vec![&env, buyer.clone()]);
+    }
+}
+
+#[test]
+fn test_session_ticket_rejected_outside_sessions_and_ungranted_sessions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let day_two_buyer = Address::generate(&env);
+
+    let event_id = create_three_day_festival(&env, &client, &organizer);
+    let full_pass = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let day_two = client.purchase_ticket(&day_two_buyer, &event_id, &100i128);
+    client.set_ticket_sessions(&organizer, &day_two, &vec![&env, 2u32]);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(
+        client.try_use_ticket(&full_pass, &organizer),
+        Err(Ok(LumentixError::SessionNotActive))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    assert_eq!(
+        client.try_use_ticket(&day_two, &organizer),
+        Err(Ok(LumentixError::SessionNotGranted))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 2500);
+    client.use_ticket(&day_two, &organizer);
+    assert!(client.get_ticket_info(&day_two).used);
+
+    assert_eq!(client.get_session_attendees(&event_id, &1u32).len(), 0);
+    assert_eq!(
+        client.get_session_attendees(&event_id, &2u32),
+        vec![&env, day_two_buyer]
+    );
+
+    let unknown_session = client.try_set_ticket_sessions(&organizer, &full_pass, &vec![&env, 4u32]);
+    assert_eq!(unknown_session, Err(Ok(LumentixError::SessionNotFound)));
+}
+
+#[test]
+fn test_add_session_rejects_overlap_and_out_of_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.add_session(&organizer, &event_id, &1000u64, &1500u64);
+
+    let overlap = client.try_add_session(&organizer, &event_id, &1400u64, &1800u64);
+    assert_eq!(overlap, Err(Ok(LumentixError::InvalidTimeRange)));
+
+    let outside = client.try_add_session(&organizer, &event_id, &1900u64, &2100u64);
+    assert_eq!(outside, Err(Ok(LumentixError::InvalidTimeRange)));
+
+    let unauthorized = client.try_add_session(&stranger, &event_id, &1600u64, &1800u64);
+    assert_eq!(unauthorized, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_partially_attended_session_ticket_cannot_be_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_three_day_festival(&env, &client, &organizer);
+    let attended = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let unused = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    client.use_ticket(&attended, &organizer);
+    assert!(!client.get_ticket_info(&attended).used);
+
+    client.cancel_event(&organizer, &event_id);
+    assert_eq!(
+        client.try_refund_ticket(&attended, &buyer),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+    client.refund_ticket(&unused, &buyer);
+}
+
+#[test]
+fn test_sessions_locked_once_tickets_sold_or_checked_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Festival"),
+        &String::from_str(&env, "Two days"),
+        &String::from_str(&env, "Fields"),
+        &1000u64,
+        &3000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+    client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    // Adding a session after a sale would let a used ticket check in again
+    assert_eq!(
+        client.try_add_session(&organizer, &event_id, &1000u64, &1999u64),
+        Err(Ok(LumentixError::SessionsLocked))
+    );
+
+    let festival_id = create_three_day_festival(&env, &client, &organizer);
+    let pass = client.purchase_ticket(&buyer, &festival_id, &100i128);
+    client.set_ticket_sessions(&organizer, &pass, &vec![&env, 1u32, 2u32]);
+
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    client.use_ticket(&pass, &organizer);
+    assert_eq!(
+        client.try_set_ticket_sessions(&organizer, &pass, &vec![&env, 1u32]),
+        Err(Ok(LumentixError::SessionsLocked))
+    );
+}
+
+#[test]
+fn test_reentry_mode_allows_scan_out_scan_in_pairs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    client.use_ticket(&ticket_id, &organizer);
+    assert_eq!(
+        client.try_scan_out(&ticket_id, &organizer),
+        Err(Ok(LumentixError::ReentryNotAllowed))
+    );
+
+    client.set_reentry_mode(&organizer, &event_id, &true);
+    client.scan_out(&ticket_id, &organizer);
+    assert_eq!(
+        client.try_scan_out(&ticket_id, &organizer),
+        Err(Ok(LumentixError::TicketNotCheckedIn))
+    );
+
+    client.use_ticket(&ticket_id, &organizer);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+
+    // Disabling re-entry blocks tickets that are currently outside
+    client.scan_out(&ticket_id, &organizer);
+    client.set_reentry_mode(&organizer, &event_id, &false);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+}
+
+// ============================================================================
+// CHECK-IN WINDOW TESTS
+// ============================================================================
+
+#[test]
+fn test_checkin_window_rejects_early_and_late_scans() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    // Event runs 1000..2000; doors open 200 before start and close at end
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_checkin_window(&organizer, &event_id, &CheckInWindow::Relative(200, 0));
+    let early = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let late = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let on_time = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    env.ledger().with_mut(|li| li.timestamp = 799);
+    assert_eq!(
+        client.try_use_ticket(&early, &organizer),
+        Err(Ok(LumentixError::OutsideCheckInWindow))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 800);
+    client.use_ticket(&on_time, &organizer);
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    assert_eq!(
+        client.try_use_ticket(&late, &organizer),
+        Err(Ok(LumentixError::OutsideCheckInWindow))
+    );
+    assert!(!client.get_ticket_info(&late).used);
+}
+
+#[test]
+fn test_early_entry_override_bypasses_window_opening_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let vip = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_checkin_window(&organizer, &event_id, &CheckInWindow::Relative(0, 0));
+    let vip_ticket = client.purchase_ticket(&vip, &event_id, &100i128);
+    let late_vip_ticket = client.purchase_ticket(&vip, &event_id, &100i128);
+
+    let unauthorized = client.try_set_early_entry(&stranger, &vip_ticket, &true);
+    assert_eq!(unauthorized, Err(Ok(LumentixError::Unauthorized)));
+
+    client.set_early_entry(&organizer, &vip_ticket, &true);
+    client.set_early_entry(&organizer, &late_vip_ticket, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.use_ticket(&vip_ticket, &organizer);
+    assert!(client.get_ticket_info(&vip_ticket).used);
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    assert_eq!(
+        client.try_use_ticket(&late_vip_ticket, &organizer),
+        Err(Ok(LumentixError::OutsideCheckInWindow))
+    );
+}
+
+#[test]
+fn test_default_checkin_window_is_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Late show"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &(10 * SECONDS_PER_DAY),
+        &(11 * SECONDS_PER_DAY),
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+    assert_eq!(
+        client.get_event(&event_id).checkin_window,
+        DEFAULT_CHECKIN_WINDOW
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 9 * SECONDS_PER_DAY - 1);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer),
+        Err(Ok(LumentixError::OutsideCheckInWindow))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 11 * SECONDS_PER_DAY + 1);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer),
+        Err(Ok(LumentixError::OutsideCheckInWindow))
+    );
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = 10 * SECONDS_PER_DAY);
+    client.use_ticket(&ticket_id, &organizer);
+}
+
+#[test]
+fn test_early_entry_admits_to_next_session() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let vip = Address::generate(&env);
+
+    let event_id = create_three_day_festival(&env, &client, &organizer);
+    client.set_checkin_window(&organizer, &event_id, &CheckInWindow::Relative(0, 0));
+    let vip_ticket = client.purchase_ticket(&vip, &event_id, &100i128);
+    let day_two_ticket = client.purchase_ticket(&vip, &event_id, &100i128);
+    client.set_ticket_sessions(&organizer, &day_two_ticket, &vec![&env, 2u32]);
+    client.set_early_entry(&organizer, &vip_ticket, &true);
+    client.set_early_entry(&organizer, &day_two_ticket, &true);
+
+    env.ledger().with_mut(|li| li.timestamp = 900);
+    client.use_ticket(&vip_ticket, &organizer);
+    client.use_ticket(&day_two_ticket, &organizer);
+    assert_eq!(
+        client.get_session_attendees(&event_id, &1u32),
+        vec![&env, vip.clone()]
+    );
+    assert_eq!(
+        client.get_session_attendees(&event_id, &2u32),
+        vec![&env, vip.clone()]
+    );
+}
+
+#[test]
+fn test_transfer_clears_early_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let vip = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_checkin_window(&organizer, &event_id, &CheckInWindow::Relative(0, 0));
+    let ticket_id = client.purchase_ticket(&vip, &event_id, &100i128);
+    client.set_early_entry(&organizer, &ticket_id, &true);
+
+    client.transfer_ticket(&ticket_id, &vip, &friend);
+
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    assert_eq!(
+        client.try_use_ticket(&ticket_id, &organizer),
+        Err(Ok(LumentixError::OutsideCheckInWindow))
+    );
+}
+
+#[test]
+fn test_resetting_checkin_window_to_anytime_accepts_any_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_checkin_window(&organizer, &event_id, &CheckInWindow::Relative(0, 0));
+    client.set_checkin_window(&organizer, &event_id, &CheckInWindow::Anytime);
+    assert_eq!(
+        client.get_event(&event_id).checkin_window,
+        CheckInWindow::Anytime
+    );
+
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.use_ticket(&ticket_id, &organizer);
+    assert!(client.get_ticket_info(&ticket_id).used);
+}
+
+// ============================================================================
+// SIGNED ENTRY PASS TESTS
+// ============================================================================
+
+fn pass_signing_key(env: &Env, seed: u8) -> (ed25519_dalek::SigningKey, BytesN<32>) {
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    (signing_key, public_key)
+}
+
+fn sign_scan(
+    env: &Env,
+    contract: &Address,
+    signing_key: &ed25519_dalek::SigningKey,
+    ticket_id: u64,
+    event_id: u64,
+    timestamp: u64,
+) -> SignedScan {
+    use ed25519_dalek::Signer;
+
+    let message = admission::pass_message(env, contract, ticket_id, event_id, timestamp);
+    let signature = signing_key.sign(&message.to_alloc_vec());
+    SignedScan {
+        ticket_id,
+        event_id,
+        timestamp,
+        signature: BytesN::from_array(env, &signature.to_bytes()),
+    }
+}
+
+#[test]
+fn test_submit_signed_scans_admits_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let alice_ticket = client.purchase_ticket(&alice, &event_id, &100i128);
+    let bob_ticket = client.purchase_ticket(&bob, &event_id, &100i128);
+
+    let (alice_key, alice_pub) = pass_signing_key(&env, 1);
+    let (bob_key, bob_pub) = pass_signing_key(&env, 2);
+    client.register_pass_key(&alice, &alice_ticket, &alice_pub);
+    client.register_pass_key(&bob, &bob_ticket, &bob_pub);
+    assert_eq!(client.get_pass_key(&alice_ticket), Some(alice_pub));
+
+    // Scans collected offline at 1100 and 1150, submitted later
+    env.ledger().with_mut(|li| li.timestamp = 1500);
+    let scans = vec![
+        &env,
+        sign_scan(
+            &env,
+            &client.address,
+            &alice_key,
+            alice_ticket,
+            event_id,
+            1100,
+        ),
+        sign_scan(&env, &client.address, &bob_key, bob_ticket, event_id, 1150),
+    ];
+    let results = client.submit_signed_scans(&organizer, &scans);
+
+    assert_eq!(results, vec![&env, CheckInResult::Ok, CheckInResult::Ok]);
+    assert!(client.get_ticket_info(&alice_ticket).used);
+    assert!(client.get_ticket_info(&bob_ticket).used);
+}
+
+#[test]
+fn test_submit_signed_scans_rejects_replay_and_future_scans() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_reentry_mode(&organizer, &event_id, &true);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let (signing_key, public_key) = pass_signing_key(&env, 3);
+    client.register_pass_key(&buyer, &ticket_id, &public_key);
+
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    let scan = sign_scan(
+        &env,
+        &client.address,
+        &signing_key,
+        ticket_id,
+        event_id,
+        1100,
+    );
+    client.submit_signed_scans(&organizer, &vec![&env, scan.clone()]);
+    client.scan_out(&ticket_id, &organizer);
+
+    // Re-submitting the same signed scan is a replay, and scans cannot be dated
+    // ahead of the ledger; neither fails the rest of the upload
+    let future = sign_scan(
+        &env,
+        &client.address,
+        &signing_key,
+        ticket_id,
+        event_id,
+        1300,
+    );
+    let fresh = sign_scan(
+        &env,
+        &client.address,
+        &signing_key,
+        ticket_id,
+        event_id,
+        1150,
+    );
+    assert_eq!(
+        client.submit_signed_scans(&organizer, &vec![&env, scan, future, fresh]),
+        vec![
+            &env,
+            CheckInResult::StaleScan,
+            CheckInResult::StaleScan,
+            CheckInResult::Ok
+        ]
+    );
+}
+
+#[test]
+fn test_submit_signed_scans_validates_key_event_and_caller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let (signing_key, public_key) = pass_signing_key(&env, 4);
+
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    let scan = sign_scan(
+        &env,
+        &client.address,
+        &signing_key,
+        ticket_id,
+        event_id,
+        1100,
+    );
+    assert_eq!(
+        client.submit_signed_scans(&organizer, &vec![&env, scan.clone()]),
+        vec![&env, CheckInResult::NoPassKey]
+    );
+
+    assert_eq!(
+        client.try_register_pass_key(&stranger, &ticket_id, &public_key),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.register_pass_key(&buyer, &ticket_id, &public_key);
+
+    assert_eq!(
+        client.submit_signed_scans(&stranger, &vec![&env, scan.clone()]),
+        vec![&env, CheckInResult::Unauthorized]
+    );
+
+    let wrong_event = sign_scan(
+        &env,
+        &client.address,
+        &signing_key,
+        ticket_id,
+        event_id + 1,
+        1100,
+    );
+    assert_eq!(
+        client.submit_signed_scans(&organizer, &vec![&env, wrong_event]),
+        vec![&env, CheckInResult::WrongEvent]
+    );
+    assert!(!client.get_ticket_info(&ticket_id).used);
+
+    // Validators of the event can upload scans too
+    let gate_agent = Address::generate(&env);
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    assert_eq!(
+        client.submit_signed_scans(&gate_agent, &vec![&env, scan]),
+        vec![&env, CheckInResult::Ok]
+    );
+    assert!(client.get_ticket_info(&ticket_id).used);
+}
+
+#[test]
+fn test_submit_signed_scans_rejects_forged_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let (_holder_key, public_key) = pass_signing_key(&env, 5);
+    let (forger_key, _) = pass_signing_key(&env, 6);
+    client.register_pass_key(&buyer, &ticket_id, &public_key);
+
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    let forged = sign_scan(
+        &env,
+        &client.address,
+        &forger_key,
+        ticket_id,
+        event_id,
+        1100,
+    );
+    assert!(client
+        .try_submit_signed_scans(&organizer, &vec![&env, forged])
+        .is_err());
+    assert!(!client.get_ticket_info(&ticket_id).used);
+}
+
+#[test]
+fn test_signed_scan_is_bound_to_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let (_other_admin, other_client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let (signing_key, public_key) = pass_signing_key(&env, 7);
+    client.register_pass_key(&buyer, &ticket_id, &public_key);
+
+    // A pass signed for another deployment does not verify here
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    let replayed = sign_scan(
+        &env,
+        &other_client.address,
+        &signing_key,
+        ticket_id,
+        event_id,
+        1100,
+    );
+    assert!(client
+        .try_submit_signed_scans(&organizer, &vec![&env, replayed])
+        .is_err());
+    assert!(!client.get_ticket_info(&ticket_id).used);
+}
+
+#[test]
+fn test_transfer_clears_pass_key() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&seller, &event_id, &100i128);
+    let (_signing_key, public_key) = pass_signing_key(&env, 7);
+    client.register_pass_key(&seller, &ticket_id, &public_key);
+
+    client.transfer_ticket(&ticket_id, &seller, &recipient);
+
+    assert_eq!(client.get_pass_key(&ticket_id), None);
+}
+
+// ============================================================================
+// BATCH CHECK-IN TESTS
+// ============================================================================
+
+#[test]
+fn test_batch_check_in_reports_per_ticket_results() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let other_event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let valid = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let used = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let refunded = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let other_event = client.purchase_ticket(&buyer, &other_event_id, &100i128);
+
+    client.use_ticket(&used, &organizer);
+    client.cancel_event(&organizer, &event_id);
+    client.refund_ticket(&refunded, &buyer);
+
+    let results = client.batch_check_in(
+        &organizer,
+        &event_id,
+        &vec![&env, valid, used, refunded, other_event, 999],
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            CheckInResult::Ok,
+            CheckInResult::AlreadyUsed,
+            CheckInResult::Refunded,
+            CheckInResult::WrongEvent,
+            CheckInResult::NotFound,
+        ]
+    );
+    assert!(client.get_ticket_info(&valid).used);
+    assert!(!client.get_ticket_info(&other_event).used);
+}
+
+#[test]
+fn test_batch_check_in_by_validator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let first = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let second = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    assert_eq!(
+        client.try_batch_check_in(&gate_agent, &event_id, &vec![&env, first]),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    assert!(client.is_validator(&event_id, &gate_agent));
+
+    let results = client.batch_check_in(&gate_agent, &event_id, &vec![&env, first, first]);
+    assert_eq!(
+        results,
+        vec![&env, CheckInResult::Ok, CheckInResult::AlreadyUsed]
+    );
+
+    client.remove_validator(&organizer, &event_id, &gate_agent);
+    assert!(!client.is_validator(&event_id, &gate_agent));
+    assert_eq!(
+        client.try_use_ticket(&second, &gate_agent),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_only_organizer_manages_validators() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    assert_eq!(
+        client.try_add_validator(&stranger, &event_id, &stranger),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert!(!client.is_validator(&event_id, &stranger));
+}
+
+// ============================================================================
+// ATTENDANCE BADGE TESTS
+// ============================================================================
+
+#[test]
+fn test_check_in_mints_badge_for_opted_in_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_badge_mode(&organizer, &event_id, &true);
+    let alice_ticket = client.purchase_ticket(&alice, &event_id, &100i128);
+    let bob_ticket = client.purchase_ticket(&bob, &event_id, &100i128);
+
+    env.ledger().with_mut(|li| li.timestamp = 1100);
+    client.use_ticket(&alice_ticket, &organizer);
+    env.ledger().with_mut(|li| li.timestamp = 1200);
+    client.batch_check_in(&organizer, &event_id, &vec![&env, bob_ticket]);
+
+    let badges = client.get_badges(&alice, &0, &10);
+    assert_eq!(badges.len(), 1);
+    let badge = badges.get(0).unwrap();
+    assert_eq!(badge.holder, alice);
+    assert_eq!(badge.event_id, event_id);
+    assert_eq!(badge.ticket_id, alice_ticket);
+    assert_eq!(badge.checked_in_at, 1100);
+
+    assert_eq!(
+        client.get_badge_holders(&event_id, &0, &10),
+        vec![&env, alice.clone(), bob.clone()]
+    );
+    assert_eq!(client.get_badge_holder_count(&event_id), 2);
+    assert_eq!(
+        client.get_badge_holders(&event_id, &1, &10),
+        vec![&env, bob.clone()]
+    );
+    assert_eq!(client.get_badge_holders(&event_id, &2, &10).len(), 0);
+    assert_eq!(client.get_badge_count(&alice), 1);
+}
+
+#[test]
+fn test_no_badge_without_opt_in_and_one_per_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let plain_event = create_and_publish_event(&env, &client, &organizer);
+    let plain_ticket = client.purchase_ticket(&buyer, &plain_event, &100i128);
+    client.use_ticket(&plain_ticket, &organizer);
+    assert_eq!(client.get_badges(&buyer, &0, &10).len(), 0);
+
+    let badge_event = create_and_publish_event(&env, &client, &organizer);
+    client.set_badge_mode(&organizer, &badge_event, &true);
+    let first = client.purchase_ticket(&buyer, &badge_event, &100i128);
+    let second = client.purchase_ticket(&buyer, &badge_event, &100i128);
+    client.use_ticket(&first, &organizer);
+    client.use_ticket(&second, &organizer);
+
+    assert_eq!(client.get_badges(&buyer, &0, &10).len(), 1);
+    assert_eq!(client.get_badge_holders(&badge_event, &0, &10).len(), 1);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_badge_mode(&stranger, &badge_event, &false),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_session_event_badge_minted_after_last_session() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_three_day_festival(&env, &client, &organizer);
+    client.set_badge_mode(&organizer, &event_id, &true);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    for (day, expected_badges) in [(1000u64, 0u32), (2000, 0), (3000, 1)] {
+        env.ledger().with_mut(|li| li.timestamp = day);
+        client.use_ticket(&ticket_id, &organizer);
+        assert_eq!(client.get_badges(&buyer, &0, &10).len(), expected_badges);
+    }
+}
+
+// ============================================================================
+// TOKEN INTERFACE TESTS
+// ============================================================================
+
+#[test]
+fn test_owner_of_and_balance_of_track_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&alice, &event_id, &100i128);
+    client.batch_purchase_tickets(&alice, &event_id, &2u32, &200i128);
+
+    assert_eq!(client.owner_of(&ticket_id), alice);
+    assert_eq!(client.balance_of(&alice), 3);
+
+    client.transfer_ticket(&ticket_id, &alice, &bob);
+
+    assert_eq!(client.owner_of(&ticket_id), bob);
+    assert_eq!(client.balance_of(&alice), 2);
+    assert_eq!(client.balance_of(&bob), 1);
+    assert_eq!(
+        client.try_owner_of(&999),
+        Err(Ok(LumentixError::TicketNotFound))
+    );
+}
+
+#[test]
+fn test_refund_decrements_balance_of_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let cancelled_event = create_and_publish_event(&env, &client, &organizer);
+    let other_event = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &cancelled_event, &100i128);
+    client.purchase_ticket(&buyer, &other_event, &100i128);
+    assert_eq!(client.balance_of(&buyer), 2);
+
+    client.cancel_event(&organizer, &cancelled_event);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(client.balance_of(&buyer), 1);
+
+    // Archiving does not count the refunded ticket a second time
+    client.archive_event(&organizer, &cancelled_event);
+    assert_eq!(client.balance_of(&buyer), 1);
+}
+
+#[test]
+fn test_approved_address_can_transfer_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+
+    assert_eq!(
+        client.try_transfer_from(&marketplace, &owner, &buyer, &ticket_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    client.approve(&owner, &ticket_id, &Some(marketplace.clone()));
+    assert_eq!(client.get_approved(&ticket_id), Some(marketplace.clone()));
+
+    client.transfer_from(&marketplace, &owner, &buyer, &ticket_id);
+    assert_eq!(client.owner_of(&ticket_id), buyer);
+
+    // Approval is cleared by the transfer
+    assert_eq!(client.get_approved(&ticket_id), None);
+    assert_eq!(
+        client.try_transfer_from(&marketplace, &buyer, &owner, &ticket_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_operator_can_approve_and_transfer_all_tickets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let delegate = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let first = client.purchase_ticket(&owner, &event_id, &100i128);
+    let second = client.purchase_ticket(&owner, &event_id, &100i128);
+
+    client.set_approval_for_all(&owner, &operator, &true);
+    assert!(client.is_approved_for_all(&owner, &operator));
+
+    client.transfer_from(&operator, &owner, &buyer, &first);
+    client.approve(&operator, &second, &Some(delegate.clone()));
+    client.transfer_from(&delegate, &owner, &buyer, &second);
+    assert_eq!(client.balance_of(&buyer), 2);
+
+    client.set_approval_for_all(&owner, &operator, &false);
+    assert!(!client.is_approved_for_all(&owner, &operator));
+}
+
+#[test]
+fn test_transfer_from_enforces_transfer_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let used = client.purchase_ticket(&owner, &event_id, &100i128);
+    let refunded = client.purchase_ticket(&owner, &event_id, &100i128);
+    client.set_approval_for_all(&owner, &operator, &true);
+
+    client.use_ticket(&used, &organizer);
+    assert_eq!(
+        client.try_transfer_from(&operator, &owner, &buyer, &used),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+
+    client.cancel_event(&organizer, &event_id);
+    assert_eq!(
+        client.try_transfer_from(&operator, &owner, &buyer, &refunded),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+
+    client.refund_ticket(&refunded, &owner);
+    assert_eq!(
+        client.try_transfer_from(&operator, &owner, &buyer, &refunded),
+        Err(Ok(LumentixError::RefundNotAllowed))
+    );
+}
+
+#[test]
+fn test_ticket_metadata_describes_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+
+    let event = client.get_event(&event_id);
+    let metadata = client.ticket_metadata(&ticket_id);
+    assert_eq!(metadata.ticket_id, ticket_id);
+    assert_eq!(metadata.event_id, event_id);
+    assert_eq!(metadata.name, event.name);
+    assert_eq!(metadata.location, event.location);
+    assert_eq!(metadata.start_time, event.start_time);
+    assert!(!metadata.used);
+}
+
+// ============================================================================
+// TRANSFER POLICY TESTS
+// ============================================================================
+
+#[test]
+fn test_soulbound_event_blocks_every_transfer_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::Soulbound);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    client.set_approval_for_all(&owner, &operator, &true);
+
+    assert_eq!(
+        client.try_transfer_ticket(&ticket_id, &owner, &recipient),
+        Err(Ok(LumentixError::TransferNotAllowed))
+    );
+    assert_eq!(
+        client.try_transfer_from(&operator, &owner, &recipient, &ticket_id),
+        Err(Ok(LumentixError::TransferNotAllowed))
+    );
+    assert_eq!(client.owner_of(&ticket_id), owner);
+}
+
+#[test]
+fn test_transfer_cutoff_before_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    // Event starts at 1000; transfers close 100 seconds before
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::UntilCutoff(100));
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+
+    env.ledger().with_mut(|li| li.timestamp = 899);
+    client.transfer_ticket(&ticket_id, &owner, &recipient);
+
+    env.ledger().with_mut(|li| li.timestamp = 900);
+    assert_eq!(
+        client.try_transfer_ticket(&ticket_id, &recipient, &owner),
+        Err(Ok(LumentixError::TransferCutoffPassed))
+    );
+}
+
+#[test]
+fn test_organizer_approved_policy_rejects_direct_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    assert_eq!(
+        client.try_set_transfer_policy(&stranger, &event_id, &TransferPolicy::Soulbound),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::OrganizerApproved);
+    assert_eq!(
+        client.get_event(&event_id).transfer_policy,
+        TransferPolicy::OrganizerApproved
+    );
+
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    assert_eq!(
+        client.try_transfer_ticket(&ticket_id, &owner, &recipient),
+        Err(Ok(LumentixError::TransferRequiresApproval))
+    );
+}
+
+// ============================================================================
+// TRANSFER REQUEST TESTS
+// ============================================================================
+
+#[test]
+fn test_transfer_request_approved_by_validator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::OrganizerApproved);
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    let ticket_id = client.purchase_ticket(&holder, &event_id, &100i128);
+
+    client.request_transfer(&holder, &ticket_id, &friend);
+    let request = client.get_transfer_request(&ticket_id);
+    assert_eq!(request.from, holder);
+    assert_eq!(request.to, friend);
+    assert_eq!(
+        client.try_request_transfer(&holder, &ticket_id, &friend),
+        Err(Ok(LumentixError::TransferRequestExists))
+    );
+
+    client.approve_transfer_request(&gate_agent, &ticket_id);
+
+    assert_eq!(client.owner_of(&ticket_id), friend);
+    assert_eq!(
+        client.try_get_transfer_request(&ticket_id),
+        Err(Ok(LumentixError::TransferRequestNotFound))
+    );
+}
+
+#[test]
+fn test_transfer_request_rejected_or_cancelled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::OrganizerApproved);
+    let ticket_id = client.purchase_ticket(&holder, &event_id, &100i128);
+
+    client.request_transfer(&holder, &ticket_id, &friend);
+    assert_eq!(
+        client.try_approve_transfer_request(&stranger, &ticket_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.reject_transfer_request(&organizer, &ticket_id);
+    assert_eq!(client.owner_of(&ticket_id), holder);
+
+    client.request_transfer(&holder, &ticket_id, &friend);
+    assert_eq!(
+        client.try_cancel_transfer_request(&friend, &ticket_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.cancel_transfer_request(&holder, &ticket_id);
+    assert_eq!(
+        client.try_approve_transfer_request(&organizer, &ticket_id),
+        Err(Ok(LumentixError::TransferRequestNotFound))
+    );
+    assert_eq!(client.owner_of(&ticket_id), holder);
+}
+
+#[test]
+fn test_transfer_request_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&holder, &event_id, &100i128);
+
+    assert_eq!(
+        client.try_request_transfer(&friend, &ticket_id, &friend),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::Soulbound);
+    assert_eq!(
+        client.try_request_transfer(&holder, &ticket_id, &friend),
+        Err(Ok(LumentixError::TransferNotAllowed))
+    );
+
+    // A pending request is dropped when the ticket changes hands directly
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::Free);
+    client.request_transfer(&holder, &ticket_id, &friend);
+    client.transfer_ticket(&ticket_id, &holder, &friend);
+    assert_eq!(
+        client.try_get_transfer_request(&ticket_id),
+        Err(Ok(LumentixError::TransferRequestNotFound))
+    );
+
+    // Approval still enforces the used-ticket rule
+    client.request_transfer(&friend, &ticket_id, &holder);
+    client.use_ticket(&ticket_id, &organizer);
+    assert_eq!(
+        client.try_approve_transfer_request(&organizer, &ticket_id),
+        Err(Ok(LumentixError::TicketAlreadyUsed))
+    );
+}
+
+// ============================================================================
+// GIFT TICKET TESTS
+// ============================================================================
+
+fn gift_code(env: &Env, secret: &str) -> (Bytes, BytesN<32>) {
+    let secret = Bytes::from_slice(env, secret.as_bytes());
+    let code_hash = env.crypto().sha256(&secret).into();
+    (secret, code_hash)
+}
+
+fn gift_claim_commitment(env: &Env, secret: &Bytes, claimer: &Address) -> BytesN<32> {
+    let mut preimage = secret.clone();
+    preimage.append(&claimer.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_gift_ticket_claimed_with_secret() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, contract_id, client) = create_test_contract_with_id(&env);
+    let organizer = Address::generate(&env);
+    let purchaser = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let (secret, code_hash) = gift_code(&env, "happy-birthday");
+
+    let ticket_id =
+        client.purchase_gift_ticket(&purchaser, &event_id, &100i128, &code_hash, &500u64);
+    assert_eq!(client.owner_of(&ticket_id), contract_id);
+    assert_eq!(client.get_gift(&code_hash).purchaser, purchaser);
+    assert_eq!(client.get_escrow_balance(&event_id), 100);
+
+    let (wrong_secret, _) = gift_code(&env, "wrong");
+    assert_eq!(
+        client.try_claim_gift(&friend, &wrong_secret),
+        Err(Ok(LumentixError::GiftNotFound))
+    );
+
+    // The claim must be committed in an earlier ledger than the reveal
+    assert_eq!(
+        client.try_claim_gift(&friend, &secret),
+        Err(Ok(LumentixError::GiftClaimNotCommitted))
+    );
+    client.commit_gift_claim(&friend, &gift_claim_commitment(&env, &secret, &friend));
+    assert_eq!(
+        client.try_claim_gift(&friend, &secret),
+        Err(Ok(LumentixError::GiftClaimNotCommitted))
+    );
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+
+    // A front-runner replaying the revealed secret has no commitment bound to them
+    let front_runner = Address::generate(&env);
+    assert_eq!(
+        client.try_claim_gift(&front_runner, &secret),
+        Err(Ok(LumentixError::GiftClaimNotCommitted))
+    );
+
+    assert_eq!(client.claim_gift(&friend, &secret), ticket_id);
+    assert_eq!(client.owner_of(&ticket_id), friend);
+    assert_eq!(client.balance_of(&friend), 1);
+    assert_eq!(
+        client.try_claim_gift(&purchaser, &secret),
+        Err(Ok(LumentixError::GiftNotFound))
+    );
+}
+
+#[test]
+fn test_expired_gift_reclaimed_by_purchaser() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let purchaser = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let (secret, code_hash) = gift_code(&env, "surprise");
+    let ticket_id =
+        client.purchase_gift_ticket(&purchaser, &event_id, &100i128, &code_hash, &500u64);
+
+    assert_eq!(
+        client.try_reclaim_gift(&purchaser, &code_hash),
+        Err(Ok(LumentixError::GiftNotExpired))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 501);
+    assert_eq!(
+        client.try_claim_gift(&friend, &secret),
+        Err(Ok(LumentixError::GiftExpired))
+    );
+    assert_eq!(
+        client.try_reclaim_gift(&friend, &code_hash),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    client.reclaim_gift(&purchaser, &code_hash);
+    assert_eq!(client.owner_of(&ticket_id), purchaser);
+}
+
+#[test]
+fn test_gift_for_cancelled_event_reclaimed_and_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let purchaser = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let (_secret, code_hash) = gift_code(&env, "cancelled");
+    let ticket_id =
+        client.purchase_gift_ticket(&purchaser, &event_id, &100i128, &code_hash, &500u64);
+
+    assert_eq!(
+        client.try_purchase_gift_ticket(&purchaser, &event_id, &100i128, &code_hash, &500u64),
+        Err(Ok(LumentixError::GiftCodeExists))
+    );
+
+    client.cancel_event(&organizer, &event_id);
+    client.reclaim_gift(&purchaser, &code_hash);
+    client.refund_ticket(&ticket_id, &purchaser);
+
+    assert!(client.get_ticket_info(&ticket_id).refunded);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+}
+
+#[test]
+fn test_gift_claim_follows_transfer_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let purchaser = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    // Soulbound tickets can never be claimed, so they cannot be gifted
+    let soulbound = create_and_publish_event(&env, &client, &organizer);
+    client.set_transfer_policy(&organizer, &soulbound, &TransferPolicy::Soulbound);
+    let (_secret, code_hash) = gift_code(&env, "soulbound");
+    assert_eq!(
+        client.try_purchase_gift_ticket(&purchaser, &soulbound, &100i128, &code_hash, &500u64),
+        Err(Ok(LumentixError::TransferNotAllowed))
+    );
+
+    // Claims close at the event's transfer cutoff
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.set_transfer_policy(&organizer, &event_id, &TransferPolicy::UntilCutoff(600));
+    let (secret, code_hash) = gift_code(&env, "cutoff");
+    let ticket_id =
+        client.purchase_gift_ticket(&purchaser, &event_id, &100i128, &code_hash, &900u64);
+    client.commit_gift_claim(&friend, &gift_claim_commitment(&env, &secret, &friend));
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1;
+        li.timestamp = 400;
+    });
+    assert_eq!(
+        client.try_claim_gift(&friend, &secret),
+        Err(Ok(LumentixError::TransferCutoffPassed))
+    );
+
+    // Reclaiming returns the ticket to its purchaser and is not held to the policy
+    env.ledger().with_mut(|li| li.timestamp = 901);
+    client.reclaim_gift(&purchaser, &code_hash);
+    assert_eq!(client.owner_of(&ticket_id), purchaser);
+}
+
+#[test]
+fn test_gift_claim_commitment_lapses_after_window() {
+    use soroban_sdk::testutils::storage::Temporary as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, contract_id, client) = create_test_contract_with_id(&env);
+    let organizer = Address::generate(&env);
+    let purchaser = Address::generate(&env);
+    let friend = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let (secret, code_hash) = gift_code(&env, "late");
+    client.purchase_gift_ticket(&purchaser, &event_id, &100i128, &code_hash, &900u64);
+
+    let commitment = gift_claim_commitment(&env, &secret, &friend);
+    client.commit_gift_claim(&friend, &commitment);
+    let ttl = env.as_contract(&contract_id, || {
+        env.storage()
+            .temporary()
+            .get_ttl(&DataKey::GiftClaim(commitment.clone()))
+    });
+    assert_eq!(ttl, GIFT_CLAIM_WINDOW);
+
+    // Once the reveal window has passed the commitment is gone
+    env.ledger()
+        .with_mut(|li| li.sequence_number += GIFT_CLAIM_WINDOW + 1);
+    assert_eq!(
+        client.try_claim_gift(&friend, &secret),
+        Err(Ok(LumentixError::GiftClaimNotCommitted))
+    );
+}
+
+// ============================================================================
+// EVENT METADATA TESTS
+// ============================================================================
+
+#[test]
+fn test_event_metadata_set_on_create_and_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://bafybeigdyrzt");
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Conference"),
+        &String::from_str(&env, "See metadata"),
+        &String::from_str(&env, "Hall"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &Some(uri.clone()),
+        &Some(hash.clone()),
+    );
+
+    let event = client.get_event(&event_id);
+    assert_eq!(event.metadata_uri, Some(uri));
+    assert_eq!(event.content_hash, Some(hash));
+
+    let new_uri = String::from_str(&env, "https://example.com/event.json");
+    let new_hash = BytesN::from_array(&env, &[2u8; 32]);
+    client.update_event(
+        &organizer,
+        &event_id,
+        &String::from_str(&env, "Conference"),
+        &String::from_str(&env, "See metadata"),
+        &String::from_str(&env, "Hall"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &Some(new_uri.clone()),
+        &Some(new_hash.clone()),
+    );
+
+    let summary = client.get_event_summary(&event_id);
+    assert_eq!(summary.id, event_id);
+    assert_eq!(summary.organizer, organizer);
+    assert_eq!(summary.ticket_price, 100);
+    assert_eq!(summary.status, EventStatus::Draft);
+    assert_eq!(summary.metadata_uri, Some(new_uri));
+    assert_eq!(summary.content_hash, Some(new_hash));
+}
+
+#[test]
+fn test_event_metadata_hash_requires_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+
+    let result = client.try_create_event(
+        &organizer,
+        &String::from_str(&env, "Conference"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Hall"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &Some(BytesN::from_array(&env, &[1u8; 32])),
+    );
+
+    assert_eq!(result, Err(Ok(LumentixError::EmptyString)));
+    assert_eq!(
+        client.try_get_event_summary(&1),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+}
+
+// ============================================================================
+// EVENT DISCOVERY TESTS
+// ============================================================================
+
+#[test]
+fn test_active_events_filtered_by_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+
+    let concert = create_and_publish_event(&env, &client, &organizer);
+    let workshop = create_and_publish_event(&env, &client, &organizer);
+    let other = create_and_publish_event(&env, &client, &organizer);
+    client.set_event_category(&organizer, &concert, &EventCategory::Concert);
+    client.set_event_category(&organizer, &workshop, &EventCategory::Workshop);
+
+    let concerts = client.get_active_events_by_category(&EventCategory::Concert, &0, &10);
+    assert_eq!(concerts.len(), 1);
+    assert_eq!(concerts.get(0).unwrap().id, concert);
+
+    // Events that never pick a category are listed under the default one
+    let others = client.get_active_events_by_category(&EventCategory::Other, &0, &10);
+    assert_eq!(others.len(), 1);
+    assert_eq!(others.get(0).unwrap().id, other);
+    assert_eq!(client.get_event(&other).category, EventCategory::Other);
+
+    // Recategorizing moves the event between indexes; cancelled events drop out
+    client.set_event_category(&organizer, &workshop, &EventCategory::Concert);
+    client.cancel_event(&organizer, &concert);
+    let concerts = client.get_active_events_by_category(&EventCategory::Concert, &0, &10);
+    assert_eq!(concerts.len(), 1);
+    assert_eq!(concerts.get(0).unwrap().id, workshop);
+    assert_eq!(
+        client
+            .get_active_events_by_category(&EventCategory::Workshop, &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_active_events_filtered_by_tag() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stellar = Symbol::new(&env, "stellar");
+    let defi = Symbol::new(&env, "defi");
+
+    let first = create_and_publish_event(&env, &client, &organizer);
+    let second = create_and_publish_event(&env, &client, &organizer);
+    client.set_event_tags(
+        &organizer,
+        &first,
+        &vec![&env, stellar.clone(), defi.clone()],
+    );
+    client.set_event_tags(&organizer, &second, &vec![&env, stellar.clone()]);
+
+    assert_eq!(client.get_active_events_by_tag(&stellar, &0, &10).len(), 2);
+    assert_eq!(client.get_active_events_by_tag(&defi, &0, &10).len(), 1);
+    assert_eq!(
+        client.get_active_events_by_tag(&stellar, &1, &10),
+        vec![&env, client.get_event(&second)]
+    );
+
+    client.set_event_tags(&organizer, &first, &Vec::new(&env));
+    assert_eq!(client.get_active_events_by_tag(&defi, &0, &10).len(), 0);
+    assert_eq!(client.get_event(&second).tags, vec![&env, stellar]);
+}
+
+#[test]
+fn test_event_tags_are_bounded_and_unique() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let mut too_many = Vec::new(&env);
+    for name in ["a", "b", "c", "d", "e", "f"] {
+        too_many.push_back(Symbol::new(&env, name));
+    }
+    assert_eq!(
+        client.try_set_event_tags(&organizer, &event_id, &too_many),
+        Err(Ok(LumentixError::InvalidTags))
+    );
+
+    let duplicate = vec![&env, Symbol::new(&env, "a"), Symbol::new(&env, "a")];
+    assert_eq!(
+        client.try_set_event_tags(&organizer, &event_id, &duplicate),
+        Err(Ok(LumentixError::InvalidTags))
+    );
+
+    assert_eq!(
+        client.try_set_event_category(&stranger, &event_id, &EventCategory::Sports),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_upcoming_events_within_days() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+
+    let day = 86_400u64;
+    let create = |start: u64| {
+        let event_id = client.create_event(
+            &organizer,
+            &String::from_str(&env, "Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &start,
+            &(start + 3600),
+            &100i128,
+            &50u32,
+            &None,
+            &None,
+        );
+        client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+        event_id
+    };
+
+    let tomorrow = create(day);
+    let next_week = create(7 * day);
+    let next_month = create(30 * day);
+
+    let upcoming = client.get_upcoming_events(&7u32, &0, &10);
+    assert_eq!(upcoming.len(), 2);
+    assert_eq!(upcoming.get(0).unwrap().id, tomorrow);
+    assert_eq!(upcoming.get(1).unwrap().id, next_week);
+
+    env.ledger().with_mut(|li| li.timestamp = 2 * day);
+    let upcoming = client.get_upcoming_events(&30u32, &0, &10);
+    assert_eq!(upcoming.len(), 2);
+    assert_eq!(upcoming.get(1).unwrap().id, next_month);
+
+    // Rescheduling a draft moves it to its new start day
+    let rescheduled = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &(60 * day),
+        &(60 * day + 3600),
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event(
+        &organizer,
+        &rescheduled,
+        &String::from_str(&env, "Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &(3 * day),
+        &(3 * day + 3600),
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&rescheduled, &EventStatus::Published, &organizer);
+    let upcoming = client.get_upcoming_events(&7u32, &0, &10);
+    assert_eq!(upcoming.len(), 2);
+    assert_eq!(upcoming.get(0).unwrap().id, rescheduled);
+    assert_eq!(upcoming.get(1).unwrap().id, next_week);
+}
+
+#[test]
+fn test_upcoming_events_paged_across_days() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+
+    let day = 86_400u64;
+    let mut event_ids = Vec::new(&env);
+    for start in [day, day + 3600, 2 * day, 3 * day] {
+        let event_id = client.create_event(
+            &organizer,
+            &String::from_str(&env, "Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &start,
+            &(start + 3600),
+            &100i128,
+            &50u32,
+            &None,
+            &None,
+        );
+        client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+        event_ids.push_back(event_id);
+    }
+
+    // Pages continue across day boundaries in start day order
+    let first = client.get_upcoming_events(&7u32, &0, &3);
+    assert_eq!(first.len(), 3);
+    assert_eq!(first.get(2).unwrap().id, event_ids.get(2).unwrap());
+    let second = client.get_upcoming_events(&7u32, &3, &3);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.get(0).unwrap().id, event_ids.get(3).unwrap());
+    assert_eq!(client.get_upcoming_events(&7u32, &4, &3).len(), 0);
+
+    // Limits above MAX_PAGE_SIZE are clamped rather than rejected
+    assert_eq!(client.get_upcoming_events(&7u32, &0, &1000).len(), 4);
+}
+
+// ============================================================================
+// EVENT SERIES TESTS
+// ============================================================================
+
+fn weekly_comedy_series(env: &Env, client: &LumentixContractClient, organizer: &Address) -> u64 {
+    let week = 7 * 86_400u64;
+    client.create_series(
+        organizer,
+        &SeriesTemplate {
+            name: String::from_str(env, "Comedy Night"),
+            description: String::from_str(env, "Stand-up every week"),
+            location: String::from_str(env, "Basement Club"),
+            duration: 3 * 3600,
+            ticket_price: 100,
+            max_tickets: 80,
+        },
+        &Recurrence {
+            start_time: week,
+            interval: week,
+            count: 4,
+        },
+    )
+}
+
+#[test]
+fn test_instantiate_series_creates_linked_draft_occurrences() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let series_id = weekly_comedy_series(&env, &client, &organizer);
+    let week = 7 * 86_400u64;
+
+    let first_batch = client.instantiate_series(&organizer, &series_id, &3u32);
+    assert_eq!(first_batch.len(), 3);
+
+    // Only one occurrence remains
+    let second_batch = client.instantiate_series(&organizer, &series_id, &3u32);
+    assert_eq!(second_batch.len(), 1);
+    assert_eq!(
+        client.try_instantiate_series(&organizer, &series_id, &1u32),
+        Err(Ok(LumentixError::SeriesExhausted))
+    );
+
+    let occurrences = client.get_series_occurrences(&series_id);
+    assert_eq!(occurrences.len(), 4);
+    for (i, event) in occurrences.iter().enumerate() {
+        let start = week + i as u64 * week;
+        assert_eq!(event.series_id, series_id);
+        assert_eq!(event.status, EventStatus::Draft);
+        assert_eq!(event.start_time, start);
+        assert_eq!(event.end_time, start + 3 * 3600);
+        assert_eq!(event.ticket_price, 100);
+        assert_eq!(event.max_tickets, 80);
+        assert_eq!(event.name, String::from_str(&env, "Comedy Night"));
+    }
+    assert_eq!(client.get_series(&series_id).event_ids.len(), 4);
+
+    // Occurrences are regular events once published
+    let first = first_batch.get(0).unwrap();
+    client.update_event_status(&first, &EventStatus::Published, &organizer);
+    let buyer = Address::generate(&env);
+    client.purchase_ticket(&buyer, &first, &100i128);
+}
+
+#[test]
+fn test_series_validation_and_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let template = SeriesTemplate {
+        name: String::from_str(&env, "Comedy Night"),
+        description: String::from_str(&env, "Stand-up"),
+        location: String::from_str(&env, "Club"),
+        duration: 7200,
+        ticket_price: 100,
+        max_tickets: 80,
+    };
+
+    // Occurrences would overlap
+    let overlapping = Recurrence {
+        start_time: 1000,
+        interval: 3600,
+        count: 4,
+    };
+    assert_eq!(
+        client.try_create_series(&organizer, &template, &overlapping),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+
+    // The first occurrence must be in the future
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    let past = Recurrence {
+        start_time: 500,
+        interval: 7200,
+        count: 4,
+    };
+    assert_eq!(
+        client.try_create_series(&organizer, &template, &past),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+
+    // The last occurrence must end within the u64 time range
+    let overflowing = Recurrence {
+        start_time: 1000,
+        interval: u64::MAX / 2,
+        count: 3,
+    };
+    assert_eq!(
+        client.try_create_series(&organizer, &template, &overflowing),
+        Err(Ok(LumentixError::InvalidTimeRange))
+    );
+
+    let empty = Recurrence {
+        start_time: 1000,
+        interval: 7200,
+        count: 0,
+    };
+    assert_eq!(
+        client.try_create_series(&organizer, &template, &empty),
+        Err(Ok(LumentixError::InvalidAmount))
+    );
+
+    let series_id = weekly_comedy_series(&env, &client, &organizer);
+    assert_eq!(
+        client.try_instantiate_series(&stranger, &series_id, &1u32),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_get_series(&99),
+        Err(Ok(LumentixError::SeriesNotFound))
+    );
+}
+
+// ============================================================================
+// PUBLISHED EVENT UPDATE TESTS
+// ============================================================================
+
+#[test]
+fn test_update_published_event_releases_second_batch_at_new_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let early_bird = Address::generate(&env);
+    let late_buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let early_ticket = client.purchase_ticket(&early_bird, &event_id, &100i128);
+
+    client.update_published_event(&organizer, &event_id, &80u32, &150i128);
+
+    let event = client.get_event(&event_id);
+    assert_eq!(event.max_tickets, 80);
+    assert_eq!(event.ticket_price, 150);
+    assert_eq!(event.start_time, 1000);
+
+    assert_eq!(
+        client.try_purchase_ticket(&late_buyer, &event_id, &100i128),
+        Err(Ok(LumentixError::InsufficientFunds))
+    );
+    let late_ticket = client.purchase_ticket(&late_buyer, &event_id, &150i128);
+
+    // Sold tickets keep the price they were bought at
+    assert_eq!(client.get_ticket_info(&early_ticket).paid_amount, 100);
+    assert_eq!(client.get_ticket_info(&late_ticket).paid_amount, 150);
+    assert_eq!(client.get_escrow_balance(&event_id), 250);
+}
+
+#[test]
+fn test_update_published_event_guards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let draft_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Draft"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    assert_eq!(
+        client.try_update_published_event(&organizer, &draft_id, &60u32, &100i128),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.batch_purchase_tickets(&buyer, &event_id, &3u32, &300i128);
+
+    assert_eq!(
+        client.try_update_published_event(&stranger, &event_id, &60u32, &100i128),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_update_published_event(&organizer, &event_id, &2u32, &100i128),
+        Err(Ok(LumentixError::CapacityExceeded))
+    );
+    assert_eq!(
+        client.try_update_published_event(&organizer, &event_id, &60u32, &0i128),
+        Err(Ok(LumentixError::InvalidAmount))
+    );
+
+    // Capacity can shrink down to exactly the tickets already sold
+    client.update_published_event(&organizer, &event_id, &3u32, &100i128);
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &event_id, &100i128),
+        Err(Ok(LumentixError::EventSoldOut))
+    );
+}
+
+// ============================================================================
+// EVENT DELETION AND ARCHIVAL TESTS
+// ============================================================================
+
+#[test]
+fn test_delete_rejects_referenced_draft() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let validator = Address::generate(&env);
+    let create_draft = || {
+        client.create_event(
+            &organizer,
+            &String::from_str(&env, "Draft"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+            &None,
+            &None,
+        )
+    };
+
+    // Validators must be removed before the draft can go
+    let with_validator = create_draft();
+    client.add_validator(&organizer, &with_validator, &validator);
+    assert_eq!(
+        client.try_delete_event(&organizer, &with_validator),
+        Err(Ok(LumentixError::EventInUse))
+    );
+    client.remove_validator(&organizer, &with_validator, &validator);
+    client.delete_event(&organizer, &with_validator);
+
+    // Bundles keep referencing their events
+    let bundled = create_draft();
+    client.create_bundle(
+        &organizer,
+        &String::from_str(&env, "Pass"),
+        &vec![&env, bundled],
+        &vec![&env, 1u32],
+        &100i128,
+        &10u32,
+    );
+    assert_eq!(
+        client.try_delete_event(&organizer, &bundled),
+        Err(Ok(LumentixError::EventInUse))
+    );
+
+    // Series occurrences are tracked by the series
+    let series_id = weekly_comedy_series(&env, &client, &organizer);
+    let occurrence = client
+        .instantiate_series(&organizer, &series_id, &1u32)
+        .get(0)
+        .unwrap();
+    assert_eq!(
+        client.try_delete_event(&organizer, &occurrence),
+        Err(Ok(LumentixError::EventInUse))
+    );
+}
+
+#[test]
+fn test_delete_draft_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let published = create_and_publish_event(&env, &client, &organizer);
+    let draft = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Draft"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_total_events(), 2);
+    client.set_event_category(&organizer, &draft, &EventCategory::Concert);
+    client.set_event_category(&organizer, &published, &EventCategory::Concert);
+
+    assert_eq!(
+        client.try_delete_event(&stranger, &draft),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_delete_event(&organizer, &published),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+
+    client.delete_event(&organizer, &draft);
+
+    assert_eq!(
+        client.try_get_event(&draft),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+    assert_eq!(client.get_total_events(), 1);
+    assert_eq!(client.get_events_by_organizer(&organizer).len(), 1);
+    // The published event takes over the deleted draft's index slot
+    assert_eq!(
+        client.get_active_events_by_category(&EventCategory::Concert, &0, &1),
+        vec![&env, client.get_event(&published)]
+    );
+}
+
+#[test]
+fn test_archive_cancelled_event_after_all_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let first = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let second = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    assert_eq!(
+        client.try_archive_event(&organizer, &event_id),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+
+    client.cancel_event(&organizer, &event_id);
+    client.refund_ticket(&first, &buyer);
+    assert_eq!(
+        client.try_archive_event(&organizer, &event_id),
+        Err(Ok(LumentixError::EventNotSettled))
+    );
+
+    client.refund_ticket(&second, &buyer);
+    client.archive_event(&organizer, &event_id);
+
+    assert_eq!(
+        client.try_get_event(&event_id),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+    assert_eq!(
+        client.try_get_ticket_info(&first),
+        Err(Ok(LumentixError::TicketNotFound))
+    );
+    assert_eq!(client.get_tickets_by_buyer(&buyer).len(), 0);
+    assert_eq!(client.balance_of(&buyer), 0);
+    assert_eq!(client.get_total_events(), 0);
+
+    let summary = client.get_archived_event(&event_id);
+    assert_eq!(summary.status, EventStatus::Cancelled);
+    assert_eq!(summary.organizer, organizer);
+    assert_eq!(summary.tickets_sold, 0);
+}
+
+#[test]
+fn test_archive_completed_event_requires_released_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.use_ticket(&ticket_id, &organizer);
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    client.complete_event(&organizer, &event_id);
+    assert_eq!(
+        client.try_archive_event(&organizer, &event_id),
+        Err(Ok(LumentixError::EventNotSettled))
+    );
+
+    client.release_escrow(&organizer, &event_id);
+    assert_eq!(
+        client.try_archive_event(&stranger, &event_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    client.archive_event(&organizer, &event_id);
+
+    assert_eq!(client.get_archived_event(&event_id).tickets_sold, 1);
+    assert_eq!(client.get_events_by_organizer(&organizer).len(), 0);
+    assert_eq!(
+        client.try_get_ticket_info(&ticket_id),
+        Err(Ok(LumentixError::TicketNotFound))
+    );
+}
+
+#[test]
+fn test_archive_event_refused_while_validators_or_gifts_remain() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let validator = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.add_validator(&organizer, &event_id, &validator);
+    let (_secret, code_hash) = gift_code(&env, "surprise");
+    let gift_ticket =
+        client.purchase_gift_ticket(&buyer, &event_id, &100i128, &code_hash, &1500u64);
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    client.complete_event(&organizer, &event_id);
+    client.release_escrow(&organizer, &event_id);
+    assert_eq!(
+        client.try_archive_event(&organizer, &event_id),
+        Err(Ok(LumentixError::EventInUse))
+    );
+
+    client.remove_validator(&organizer, &event_id, &validator);
+    assert_eq!(
+        client.try_archive_event(&organizer, &event_id),
+        Err(Ok(LumentixError::EventInUse))
+    );
+
+    // Once the gift is reclaimed nothing refers to the event any more
+    client.reclaim_gift(&buyer, &code_hash);
+    client.archive_event(&organizer, &event_id);
+    assert!(!client.is_validator(&event_id, &validator));
+    assert_eq!(
+        client.try_get_gift(&code_hash),
+        Err(Ok(LumentixError::GiftNotFound))
+    );
+    assert_eq!(
+        client.try_get_ticket_info(&gift_ticket),
+        Err(Ok(LumentixError::TicketNotFound))
+    );
+}
+
+// ============================================================================
+// TTL KEEPER TESTS
+// ============================================================================
+
+#[test]
+fn test_keep_event_alive_covers_event_escrow_and_tickets() {
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, contract_id, client) = create_test_contract_with_id(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    // An event ending 90 days from now needs more than the default lifetime
+    let end_time = 90 * SECONDS_PER_DAY;
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Far Future"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &(end_time - 3600),
+        &end_time,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+    let first = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let second = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    let progress = client.keep_event_alive(&event_id, &0u32, &10u32);
+    assert!(progress.done);
+    assert_eq!(progress.next_index, 2);
+    let needed = ((end_time + DISPUTE_BUFFER) / SECONDS_PER_LEDGER) as u32;
+    assert!(progress.live_until_ledger >= env.ledger().sequence() + needed);
+
+    // A ticket sold after the keeper ran inherits the event's liveness
+    let late = client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(storage.get_ttl(&DataKey::Event(event_id)) >= needed);
+        assert!(storage.get_ttl(&DataKey::Escrow(event_id)) >= needed);
+        for ticket_id in [first, second, late] {
+            assert!(storage.get_ttl(&DataKey::Ticket(ticket_id)) >= needed);
+        }
+        assert!(storage.get_ttl(&DataKey::EventTicket(event_id, 0)) >= needed);
+        // The records are only reachable while the contract instance is live
+        assert!(env.storage().instance().get_ttl() >= needed);
+    });
+}
+
+#[test]
+fn test_keep_event_alive_pages_through_tickets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    for _ in 0..3 {
+        client.purchase_ticket(&buyer, &event_id, &100i128);
+    }
+
+    let first = client.keep_event_alive(&event_id, &0u32, &2u32);
+    assert_eq!(first.next_index, 2);
+    assert!(!first.done);
+
+    // An unfinished run is not recorded and pages must continue at the cursor
+    assert_eq!(
+        client
+            .get_events_near_expiry(&1000u32, &event_id, &1u32)
+            .get(0)
+            .unwrap()
+            .live_until_ledger,
+        0
+    );
+    assert_eq!(
+        client.try_keep_event_alive(&event_id, &3u32, &2u32),
+        Err(Ok(LumentixError::InvalidKeepAliveCursor))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    let last = client.keep_event_alive(&event_id, &2u32, &2u32);
+    assert_eq!(last.next_index, 3);
+    assert!(last.done);
+    assert_eq!(last.live_until_ledger, first.live_until_ledger);
+
+    let expiring = client.get_events_near_expiry(&1000u32, &event_id, &1u32);
+    assert_eq!(expiring.len(), 0);
+
+    // The run is finished, so only a new run may start
+    assert_eq!(
+        client.try_keep_event_alive(&event_id, &3u32, &2u32),
+        Err(Ok(LumentixError::InvalidKeepAliveCursor))
+    );
+}
+
+#[test]
+fn test_keep_event_alive_unknown_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+
+    assert_eq!(
+        client.try_keep_event_alive(&99u64, &0u32, &10u32),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+}
+
+#[test]
+fn test_get_events_near_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+
+    let kept = create_and_publish_event(&env, &client, &organizer);
+    let neglected = create_and_publish_event(&env, &client, &organizer);
+
+    // Events the keeper never ran for are reported
+    let expiring = client.get_events_near_expiry(&1000u32, &1u64, &10u32);
+    assert_eq!(expiring.len(), 2);
+    assert_eq!(expiring.get(0).unwrap().live_until_ledger, 0);
+    assert_eq!(
+        expiring.get(0).unwrap().required_until,
+        2000 + DISPUTE_BUFFER
+    );
+
+    let live_until = client
+        .keep_event_alive(&kept, &0u32, &10u32)
+        .live_until_ledger;
+
+    let expiring = client.get_events_near_expiry(&1000u32, &1u64, &10u32);
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring.get(0).unwrap().event_id, neglected);
+
+    // A horizon past the kept ledger reports both again
+    let horizon = live_until - env.ledger().sequence() + 1;
+    let expiring = client.get_events_near_expiry(&horizon, &1u64, &10u32);
+    assert_eq!(expiring.len(), 2);
+    assert_eq!(expiring.get(0).unwrap().live_until_ledger, live_until);
+}
+
+// ============================================================================
+// STORAGE FOOTPRINT TESTS
+// ============================================================================
+
+#[test]
+fn test_instance_storage_holds_only_config() {
+    use soroban_sdk::{testutils::storage::Instance as _, IntoVal};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, contract_id, client) = create_test_contract_with_id(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    client.set_platform_fee(&admin, &250u32);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    // Counters and the platform balance live outside instance storage
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance().all();
+        assert_eq!(instance.len(), 3);
+        assert!(instance.contains_key(DataKey::Admin.into_val(&env)));
+        assert!(!instance.contains_key(DataKey::EventCounter.into_val(&env)));
+        assert!(!instance.contains_key(DataKey::PlatformBalance.into_val(&env)));
+    });
+    assert_eq!(client.get_platform_balance(), 2);
+}
+
+#[test]
+fn test_read_only_call_footprint() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    client.purchase_ticket(&buyer, &event_id, &100i128);
+
+    // Counters live outside instance storage and reads never extend, so a lookup
+    // touches only the contract instance and the event itself
+    client.get_event(&event_id);
+    let resources = env.cost_estimate().resources();
+    assert_eq!(resources.memory_read_entries, 2);
+    assert_eq!(resources.write_entries, 0);
+    assert_eq!(resources.persistent_entry_rent_bumps, 0);
+
+    // Counter queries add only the counters they read
+    client.get_total_events();
+    let resources = env.cost_estimate().resources();
+    assert_eq!(resources.memory_read_entries, 3);
+    assert_eq!(resources.write_entries, 0);
+}
+
+#[test]
+fn test_ttl_keepers_extend_instance() {
+    use soroban_sdk::testutils::storage::Instance as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, contract_id, client) = create_test_contract_with_id(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let instance_ttl = || env.as_contract(&contract_id, || env.storage().instance().get_ttl());
+    let age_instance = || {
+        env.ledger()
+            .with_mut(|li| li.sequence_number += 2 * TEMPORARY_LIFETIME);
+        assert!(instance_ttl() < INSTANCE_LIFETIME - TEMPORARY_LIFETIME);
+    };
+
+    // Each keeper extends the instance the records it keeps live depend on
+    age_instance();
+    client.bump_event_ttl(&event_id);
+    assert_eq!(instance_ttl(), INSTANCE_LIFETIME);
+
+    age_instance();
+    client.bump_ticket_ttl(&ticket_id);
+    assert_eq!(instance_ttl(), INSTANCE_LIFETIME);
+
+    age_instance();
+    client.keep_event_alive(&event_id, &0u32, &10u32);
+    assert_eq!(instance_ttl(), INSTANCE_LIFETIME);
+}
+
+#[test]
+fn test_persistent_ttl_extends_on_write_past_threshold() {
+    use soroban_sdk::testutils::storage::Persistent as _;
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, contract_id, client) = create_test_contract_with_id(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let event_ttl = || {
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get_ttl(&DataKey::Event(event_id))
+        })
+    };
+    assert_eq!(event_ttl(), PERSISTENT_LIFETIME);
+
+    // Reads never extend
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    client.get_event(&event_id);
+    assert_eq!(event_ttl(), PERSISTENT_LIFETIME - 100);
+
+    // Writes within a day of the full lifetime leave the TTL alone
+    client.set_event_category(&organizer, &event_id, &EventCategory::Concert);
+    assert_eq!(event_ttl(), PERSISTENT_LIFETIME - 100);
+
+    // Writes past the bump threshold extend back to the full lifetime
+    env.ledger()
+        .with_mut(|li| li.sequence_number += TEMPORARY_LIFETIME);
+    client.set_event_category(&organizer, &event_id, &EventCategory::Sports);
+    assert_eq!(event_ttl(), PERSISTENT_LIFETIME);
+}
+
+// ============================================================================
+// STORAGE MIGRATION TESTS
+// ============================================================================
+
+#[test]
+fn test_migrate_storage_skips_records_that_do_not_decode() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(LumentixContract, ());
+    let client = LumentixContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let legacy_event = LegacyEvent {
+        id: 1,
+        organizer: organizer.clone(),
+        name: String::from_str(&env, "Launch"),
+        description: String::from_str(&env, "Description"),
+        location: String::from_str(&env, "Location"),
+        start_time: 1000,
+        end_time: 2000,
+        ticket_price: 100,
+        max_tickets: 50,
+        tickets_sold: 1,
+        status: EventStatus::Published,
+    };
+    let legacy_ticket = LegacyTicket {
+        id: 1,
+        event_id: 1,
+        owner: buyer.clone(),
+        purchase_time: 500,
+        used: false,
+        refunded: false,
+    };
+    // A struct with as many fields as a ticket, but not a ticket's
+    let mut foreign_record = soroban_sdk::Map::<Symbol, u32>::new(&env);
+    for name in ["a", "b", "c", "d", "e", "f"] {
+        foreign_record.set(Symbol::new(&env, name), 0);
+    }
+
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        instance.set(&"INIT", &true);
+        instance.set(&"ADMIN", &admin);
+        instance.set(&"EVENT_CTR", &3u64);
+        instance.set(&"TICKET_CTR", &3u64);
+        instance.set(&"PLATFORM_FEE_BPS", &250u32);
+        instance.set(&"PLATFORM_BAL", &4i128);
+
+        let persistent = env.storage().persistent();
+        persistent.set(&("EVENT_", 1u64), &legacy_event);
+        persistent.set(&("ESCROW_", 1u64), &98i128);
+        persistent.set(&("EVENT_", 2u64), &42u32);
+        persistent.set(&("ESCROW_", 2u64), &50i128);
+        persistent.set(&("TICKET_", 1u64), &legacy_ticket);
+        persistent.set(&("TICKET_", 2u64), &foreign_record);
+    });
+
+    // The typed layout cannot see legacy records, except counters read through
+    // their legacy keys
+    assert!(!client.get_is_initialized());
+    assert_eq!(client.get_total_events(), 2);
+    assert_eq!(client.get_platform_balance(), 4);
+    assert_eq!(
+        client.try_get_event(&1),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+    assert_eq!(
+        client.try_purchase_ticket(&buyer, &1, &100i128),
+        Err(Ok(LumentixError::MigrationPending))
+    );
+    assert_eq!(
+        client.try_migrate_storage(&Address::generate(&env), &10u32),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    // Config, then the first event with its escrow, leaving the rest for the next batch
+    let progress = client.migrate_storage(&admin, &2u32);
+    assert_eq!(progress.phase, MigrationPhase::Events);
+    assert_eq!(progress.next_id, 2);
+    assert_eq!(progress.moved, 8);
+    assert_eq!(progress.skipped, 0);
+
+    // The corrupt event and the foreign ticket are reported instead of trapping
+    let progress = client.migrate_storage(&admin, &10u32);
+    assert_eq!(progress.phase, MigrationPhase::Done);
+    assert_eq!(progress.moved, 1);
+    assert_eq!(progress.skipped, 2);
+    let skip_topic = xdr::ScVal::Symbol(xdr::ScSymbol("migskip".try_into().unwrap()));
+    let skips = env
+        .events()
+        .all()
+        .events()
+        .iter()
+        .filter(|event| match &event.body {
+            xdr::ContractEventBody::V0(body) => body.topics[0] == skip_topic,
+        })
+        .count();
+    assert_eq!(skips, 2);
+
+    // Skipped records stay under their legacy keys
+    env.as_contract(&contract_id, || {
+        let persistent = env.storage().persistent();
+        assert!(persistent.has(&("EVENT_", 2u64)));
+        assert!(persistent.has(&("ESCROW_", 2u64)));
+        assert!(persistent.has(&("TICKET_", 2u64)));
+    });
+    assert_eq!(
+        client.try_get_event(&2),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+
+    assert!(client.get_is_initialized());
+    assert_eq!(client.get_platform_fee(), 250);
+    assert_eq!(client.get_escrow_balance(&1), 98);
+    assert_eq!(client.owner_of(&1), buyer);
+    assert_eq!(client.balance_of(&buyer), 1);
+
+    // Nothing is left to move on a second run, and the contract is usable again
+    assert_eq!(client.migrate_storage(&admin, &10u32).moved, 0);
+    assert_eq!(client.purchase_ticket(&buyer, &1, &100i128), 3);
+}
+
+#[test]
+fn test_migrate_storage_from_original_layout() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // A deployment of the original contract: no `initialize` under the typed layout
+    let contract_id = env.register(LumentixContract, ());
+    let client = LumentixContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let legacy_event = LegacyEvent {
+        id: 1,
+        organizer: organizer.clone(),
+        name: String::from_str(&env, "Launch"),
+        description: String::from_str(&env, "Description"),
+        location: String::from_str(&env, "Location"),
+        start_time: 1000,
+        end_time: 2000,
+        ticket_price: 100,
+        max_tickets: 50,
+        tickets_sold: 2,
+        status: EventStatus::Published,
+    };
+    let legacy_ticket = |id: u64, refunded: bool| LegacyTicket {
+        id,
+        event_id: 1,
+        owner: buyer.clone(),
+        purchase_time: 500,
+        used: false,
+        refunded,
+    };
+
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        instance.set(&"INIT", &true);
+        instance.set(&"ADMIN", &admin);
+        instance.set(&"EVENT_CTR", &2u64);
+        instance.set(&"TICKET_CTR", &4u64);
+        instance.set(&"PLATFORM_FEE_BPS", &250u32);
+        instance.set(&"PLATFORM_BAL", &5i128);
+
+        let persistent = env.storage().persistent();
+        persistent.set(&("EVENT_", 1u64), &legacy_event);
+        persistent.set(&("ESCROW_", 1u64), &195i128);
+        persistent.set(&("TICKET_", 1u64), &legacy_ticket(1, false));
+        persistent.set(&("TICKET_", 2u64), &legacy_ticket(2, false));
+        persistent.set(&("TICKET_", 3u64), &legacy_ticket(3, true));
+    });
+
+    // An upgraded deployment cannot be taken over or written to before migrating
+    assert_eq!(
+        client.try_initialize(&buyer),
+        Err(Ok(LumentixError::AlreadyInitialized))
+    );
+    assert_eq!(
+        client.try_create_event(
+            &organizer,
+            &String::from_str(&env, "Event"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &1000u64,
+            &2000u64,
+            &100i128,
+            &50u32,
+            &None,
+            &None,
+        ),
+        Err(Ok(LumentixError::MigrationPending))
+    );
+
+    let mut progress = client.migrate_storage(&admin, &1u32);
+    assert_eq!(progress.phase, MigrationPhase::Config);
+    while progress.phase != MigrationPhase::Done {
+        progress = client.migrate_storage(&admin, &1u32);
+    }
+
+    // New fields take the defaults new events and tickets get
+    let event = client.get_event(&1);
+    assert_eq!(event.name, legacy_event.name);
+    assert_eq!(event.tickets_sold, 2);
+    assert_eq!(event.checkin_window, CheckInWindow::Relative(86_400, 0));
+    assert_eq!(event.category, EventCategory::Other);
+    assert_eq!(event.series_id, 0);
+    assert_eq!(client.get_upcoming_events(&1u32, &0, &10).len(), 1);
+
+    let ticket = client.get_ticket_info(&1);
+    assert_eq!(ticket.owner, buyer);
+    assert_eq!(ticket.paid_amount, 100);
+    assert_eq!(ticket.platform_fee, 2);
+    assert_eq!(ticket.escrow_amount, 98);
+    assert!(client.get_ticket_info(&3).refunded);
+    assert_eq!(client.balance_of(&buyer), 2);
+    assert_eq!(client.get_escrow_balance(&1), 195);
+    assert_eq!(client.get_platform_balance(), 5);
+
+    // Counters continue from the legacy values
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.purchase_ticket(&buyer, &1, &100i128), 4);
+    assert_eq!(client.get_event(&1).tickets_sold, 3);
+    assert_eq!(client.get_tickets_by_event(&1).len(), 4);
+}
+
+// ============================================================================
+// MULTISIG ESCROW TESTS
+// ============================================================================
+
+fn create_event_with_signers(
+    env: &Env,
+    client: &LumentixContractClient,
+    caller: &Address,
+    organizer: &Address,
+    signers: &Vec<Address>,
+    threshold: u32,
+) -> u64 {
+    let event_id = client.create_event(
+        organizer,
+        &String::from_str(env, "Gala"),
+        &String::from_str(env, "Description"),
+        &String::from_str(env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+    client.publish_event(caller, &event_id, signers, &threshold);
+    event_id
+}
+
+#[test]
+fn test_publish_event_with_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let signers = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    let event_id = create_event_with_signers(&env, &client, &organizer, &organizer, &signers, 2);
+    assert_eq!(client.get_event_status(&event_id), EventStatus::Published);
+    let config = client.get_escrow_signers(&event_id);
+    assert_eq!(config.signers, signers);
+    assert_eq!(config.threshold, 2);
+
+    // The admin can publish on the organizer's behalf
+    let event_id = create_event_with_signers(&env, &client, &admin, &organizer, &signers, 1);
+    assert_eq!(client.get_escrow_signers(&event_id).threshold, 1);
+
+    // Signers can only be attached at publish time
+    assert_eq!(
+        client.try_publish_event(&organizer, &event_id, &signers, &2),
+        Err(Ok(LumentixError::InvalidStatusTransition))
+    );
+
+    // Events published without signers have no signer set
+    let plain_id = create_and_publish_event(&env, &client, &organizer);
+    assert_eq!(
+        client.try_get_escrow_signers(&plain_id),
+        Err(Ok(LumentixError::EscrowConfigNotFound))
+    );
+}
+
+#[test]
+fn test_publish_event_validates_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let signer = Address::generate(&env);
+
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Gala"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &50u32,
+        &None,
+        &None,
+    );
+
+    let signers = vec![&env, signer.clone()];
+    assert_eq!(
+        client.try_publish_event(&outsider, &event_id, &signers, &1),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_publish_event(&organizer, &event_id, &signers, &0),
+        Err(Ok(LumentixError::InvalidThreshold))
+    );
+    assert_eq!(
+        client.try_publish_event(&organizer, &event_id, &signers, &2),
+        Err(Ok(LumentixError::InvalidThreshold))
+    );
+
+    // A duplicated signer cannot count twice towards the quorum
+    let duplicated = vec![&env, signer.clone(), signer.clone()];
+    assert_eq!(
+        client.try_publish_event(&organizer, &event_id, &duplicated, &2),
+        Err(Ok(LumentixError::InvalidAddress))
+    );
+    assert_eq!(client.get_event_status(&event_id), EventStatus::Draft);
+}
+
+#[test]
+fn test_release_escrow_requires_signer_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let signer3 = Address::generate(&env);
+    let signers = vec![&env, signer1.clone(), signer2.clone(), signer3.clone()];
+
+    let event_id = create_event_with_signers(&env, &client, &organizer, &organizer, &signers, 2);
+    client.purchase_ticket(&buyer, &event_id, &100i128);
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    client.complete_event(&organizer, &event_id);
+
+    assert_eq!(
+        client.try_release_escrow(&organizer, &event_id),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+
+    client.approve_escrow(
+        &signer1,
+        &event_id,
+        &EscrowAction::Release,
+        &organizer,
+        &100,
+    );
+    assert_eq!(
+        client.try_release_escrow(&organizer, &event_id),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+
+    // Approvals for a different action, recipient or amount do not count
+    client.approve_escrow(
+        &signer2,
+        &event_id,
+        &EscrowAction::Withdraw,
+        &organizer,
+        &100,
+    );
+    client.approve_escrow(&signer3, &event_id, &EscrowAction::Release, &buyer, &100);
+    assert_eq!(
+        client.try_release_escrow(&organizer, &event_id),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+
+    client.approve_escrow(
+        &signer2,
+        &event_id,
+        &EscrowAction::Release,
+        &organizer,
+        &100,
+    );
+    assert_eq!(client.release_escrow(&organizer, &event_id), 100);
+
+    // Approvals are consumed by the release
+    assert_eq!(client.get_escrow_approval(&event_id, &signer1), None);
+    assert_eq!(client.get_escrow_approval(&event_id, &signer3), None);
+}
+
+#[test]
+fn test_withdraw_funds_requires_signer_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let signers = vec![&env, signer1.clone(), signer2.clone()];
+
+    let event_id = create_event_with_signers(&env, &client, &admin, &organizer, &signers, 2);
+    client.deposit_funds(&organizer, &event_id, &500i128);
+
+    assert_eq!(
+        client.try_approve_escrow(&outsider, &event_id, &EscrowAction::Withdraw, &admin, &200),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_approve_escrow(&signer1, &event_id, &EscrowAction::Withdraw, &admin, &0),
+        Err(Ok(LumentixError::InvalidAmount))
+    );
+
+    client.approve_escrow(&signer1, &event_id, &EscrowAction::Withdraw, &admin, &200);
+    client.approve_escrow(&signer2, &event_id, &EscrowAction::Withdraw, &admin, &200);
+
+    // The approved recipient and amount are binding
+    assert_eq!(
+        client.try_withdraw_funds(&organizer, &event_id, &200),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+    assert_eq!(
+        client.try_withdraw_funds(&admin, &event_id, &300),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+
+    // Only signers of an existing event can revoke
+    assert_eq!(
+        client.try_revoke_escrow_approval(&signer2, &99u64),
+        Err(Ok(LumentixError::EventNotFound))
+    );
+    assert_eq!(
+        client.try_revoke_escrow_approval(&outsider, &event_id),
+        Err(Ok(LumentixError::Unauthorized))
+    );
+
+    // A revoked approval no longer counts
+    client.revoke_escrow_approval(&signer2, &event_id);
+    assert_eq!(
+        client.try_revoke_escrow_approval(&signer2, &event_id),
+        Err(Ok(LumentixError::EscrowApprovalNotFound))
+    );
+    assert_eq!(
+        client.try_withdraw_funds(&admin, &event_id, &200),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+
+    client.approve_escrow(&signer2, &event_id, &EscrowAction::Withdraw, &admin, &200);
+    assert_eq!(client.withdraw_funds(&admin, &event_id, &200), 300);
+    assert_eq!(
+        client.try_withdraw_funds(&admin, &event_id, &200),
+        Err(Ok(LumentixError::ThresholdNotMet))
+    );
+}
+
+#[test]
+fn test_approve_escrow_without_signers_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    assert_eq!(
+        client.try_approve_escrow(
+            &organizer,
+            &event_id,
+            &EscrowAction::Release,
+            &organizer,
+            &100
+        ),
+        Err(Ok(LumentixError::EscrowConfigNotFound))
+    );
+}
@@ -0,0 +1,93 @@
+use crate::error::LumentixError;
+use crate::events::TicketTransferred;
+use crate::storage;
+use crate::types::{Event, EventStatus, Ticket, TransferPolicy};
+use soroban_sdk::{Address, Env};
+
+/// Whether `spender` may move a ticket on its owner's behalf: the owner itself,
+/// the ticket's approved address, or an operator approved for all of the owner's tickets.
+pub fn is_approved_or_owner(env: &Env, ticket: &Ticket, spender: &Address) -> bool {
+    ticket.owner == *spender
+        || storage::get_ticket_approval(env, ticket.id).as_ref() == Some(spender)
+        || storage::is_operator(env, &ticket.owner, spender)
+}
+
+/// Ensure a ticket can change hands: it must not be used or refunded, and its
+/// event must be Published. Returns the ticket's event.
+pub fn check_transferable(env: &Env, ticket: &Ticket) -> Result<Event, LumentixError> {
+    if ticket.used {
+        return Err(LumentixError::TicketAlreadyUsed);
+    }
+    if ticket.refunded {
+        return Err(LumentixError::RefundNotAllowed);
+    }
+
+    let event = storage::get_event(env, ticket.event_id)?;
+    if event.status != EventStatus::Published {
+        return Err(LumentixError::InvalidStatusTransition);
+    }
+    Ok(event)
+}
+
+/// Ensure the event's transfer policy allows a transfer now.
+/// Organizer-approved transfers bypass the cutoff and approval requirement,
+/// but never a soulbound policy.
+pub fn check_policy(
+    env: &Env,
+    event: &Event,
+    organizer_approved: bool,
+) -> Result<(), LumentixError> {
+    match event.transfer_policy {
+        TransferPolicy::Soulbound => Err(LumentixError::TransferNotAllowed),
+        _ if organizer_approved => Ok(()),
+        TransferPolicy::Free => Ok(()),
+        TransferPolicy::UntilCutoff(before_start) => {
+            let cutoff = event.start_time.saturating_sub(before_start);
+            if env.ledger().timestamp() >= cutoff {
+                return Err(LumentixError::TransferCutoffPassed);
+            }
+            Ok(())
+        }
+        TransferPolicy::OrganizerApproved => Err(LumentixError::TransferRequiresApproval),
+    }
+}
+
+/// Move a ticket to `to`, enforcing the rules shared by every transfer path:
+/// the ticket must be transferable and the event's transfer policy must allow
+/// the transfer, with or without organizer approval.
+///
+/// Reassigns the ticket and emits `TicketTransferred`.
+pub fn transfer(
+    env: &Env,
+    ticket: &mut Ticket,
+    to: &Address,
+    organizer_approved: bool,
+) -> Result<(), LumentixError> {
+    let event = check_transferable(env, ticket)?;
+    check_policy(env, &event, organizer_approved)?;
+
+    let from = ticket.owner.clone();
+    reassign(env, ticket, to);
+
+    // Emit TicketTransferred event
+    TicketTransferred::emit(env, ticket.id, ticket.event_id, from, to.clone());
+
+    Ok(())
+}
+
+/// Change a ticket's owner without transfer checks (e.g. returning a gift ticket to its purchaser).
+/// Clears the ticket's approval, pending transfer request, entry pass key and early entry,
+/// updates owner balances and persists the ticket.
+pub fn reassign(env: &Env, ticket: &mut Ticket, to: &Address) {
+    let from = ticket.owner.clone();
+    ticket.owner = to.clone();
+    storage::set_ticket(env, ticket.id, ticket);
+
+    // Approvals, requests, the pass key and early entry belong to the previous holder
+    storage::remove_ticket_approval(env, ticket.id);
+    storage::remove_transfer_request(env, ticket.id);
+    storage::remove_pass_key(env, ticket.id);
+    storage::set_early_entry(env, ticket.id, false);
+    storage::decrement_balance(env, &from);
+    storage::increment_balance(env, to);
+}
@@ -1,9 +1,52 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
 
 pub const INSTANCE_LIFETIME: u32 = 535_680; // ~30 days
 pub const PERSISTENT_LIFETIME: u32 = 535_680; // ~30 days
 pub const TEMPORARY_LIFETIME: u32 = 17_280; // ~1 day
 
+/// Entries are only re-extended once their TTL drops a day below the full lifetime
+pub const INSTANCE_BUMP_THRESHOLD: u32 = INSTANCE_LIFETIME - TEMPORARY_LIFETIME;
+pub const PERSISTENT_BUMP_THRESHOLD: u32 = PERSISTENT_LIFETIME - TEMPORARY_LIFETIME;
+
+/// Ledgers a gift claim commitment can be revealed in before it expires
+pub const GIFT_CLAIM_WINDOW: u32 = TEMPORARY_LIFETIME;
+
+/// Maximum number of events a single bundle can link
+pub const MAX_BUNDLE_EVENTS: u32 = 20;
+
+/// Maximum number of admission sessions per event
+pub const MAX_EVENT_SESSIONS: u32 = 50;
+
+/// Session id used for admission records of events without sessions
+pub const NO_SESSION: u32 = 0;
+
+/// Maximum number of occurrences in an event series
+pub const MAX_SERIES_OCCURRENCES: u32 = 100;
+
+/// `Event::series_id` of events that are not part of a series
+pub const NO_SERIES: u64 = 0;
+
+/// Maximum number of records returned by one page of a paginated query
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of days `get_upcoming_events` looks ahead
+pub const MAX_UPCOMING_DAYS: u32 = 90;
+
+/// Maximum number of discovery tags on an event
+pub const MAX_EVENT_TAGS: u32 = 5;
+
+/// Seconds in a day, for "upcoming in the next N days" queries
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Check-in window of new events: doors open a day before start and close at end
+pub const DEFAULT_CHECKIN_WINDOW: CheckInWindow = CheckInWindow::Relative(SECONDS_PER_DAY, 0);
+
+/// Expected ledger close time, used to convert timestamps into TTL ledgers
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
+/// Time after an event ends during which its records must stay live for refunds and disputes
+pub const DISPUTE_BUFFER: u64 = 14 * SECONDS_PER_DAY;
+
 /// Event status enum mirroring backend statuses
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,6 +57,44 @@ pub enum EventStatus {
     Cancelled,
 }
 
+/// When check-in is accepted for an event
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckInWindow {
+    /// Check-ins are accepted at any time
+    Anytime,
+    /// Check-in opens the given seconds before `start_time` and closes the
+    /// given seconds after `end_time`, e.g. doors open 2h before start: (7200, 0)
+    Relative(u64, u64),
+}
+
+/// Discovery category of an event
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventCategory {
+    Conference,
+    Workshop,
+    Meetup,
+    Concert,
+    Sports,
+    Festival,
+    Other,
+}
+
+/// Who may transfer an event's tickets, and when
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferPolicy {
+    /// Tickets transfer freely
+    Free,
+    /// Tickets transfer freely until the given seconds before `start_time`
+    UntilCutoff(u64),
+    /// Tickets only transfer through organizer-approved transfer requests
+    OrganizerApproved,
+    /// Tickets never transfer (e.g. named conference badges)
+    Soulbound,
+}
+
 /// Event structure
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -29,6 +110,137 @@ pub struct Event {
     pub max_tickets: u32,
     pub tickets_sold: u32,
     pub status: EventStatus,
+    /// Whether a checked-in ticket may scan out and back in
+    pub reentry_allowed: bool,
+    /// When check-in is accepted
+    pub checkin_window: CheckInWindow,
+    /// Whether attendees receive a proof-of-attendance badge at check-in
+    pub badges_enabled: bool,
+    /// Who may transfer tickets, and when
+    pub transfer_policy: TransferPolicy,
+    /// Off-chain metadata (images, long description), e.g. an IPFS or HTTPS URI
+    pub metadata_uri: Option<String>,
+    /// sha256 of the off-chain metadata, for verification
+    pub content_hash: Option<BytesN<32>>,
+    /// Discovery category
+    pub category: EventCategory,
+    /// Discovery tags, at most MAX_EVENT_TAGS
+    pub tags: Vec<Symbol>,
+    /// Series this event is an occurrence of, or NO_SERIES
+    pub series_id: u64,
+}
+
+/// Fields shared by every occurrence of an event series
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeriesTemplate {
+    pub name: String,
+    pub description: String,
+    pub location: String,
+    /// Length of each occurrence in seconds
+    pub duration: u64,
+    pub ticket_price: i128,
+    pub max_tickets: u32,
+}
+
+/// When the occurrences of an event series take place
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recurrence {
+    /// Start time of the first occurrence
+    pub start_time: u64,
+    /// Seconds between the starts of consecutive occurrences
+    pub interval: u64,
+    /// Total number of occurrences
+    pub count: u32,
+}
+
+/// Recurring event series (e.g. weekly comedy nights)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSeries {
+    pub id: u64,
+    pub organizer: Address,
+    pub template: SeriesTemplate,
+    pub recurrence: Recurrence,
+    /// Ids of the occurrences instantiated so far, in order
+    pub event_ids: Vec<u64>,
+}
+
+/// Storage liveness of an event and its tickets, as guaranteed by the TTL keeper
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpiringEvent {
+    pub event_id: u64,
+    /// Ledger guaranteed by the last complete keeper run (0 if none). This is the keeper's
+    /// bookkeeping: the actual TTL may be higher if records were extended by other means
+    pub live_until_ledger: u32,
+    /// Timestamp until which the records are needed (`end_time` plus the dispute buffer)
+    pub required_until: u64,
+}
+
+/// Progress of a `keep_event_alive` run after one page of tickets
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeepAliveProgress {
+    /// Ticket index slot the next page starts at
+    pub next_index: u32,
+    /// Ledger the run guarantees the event, its escrow and the covered tickets live until
+    pub live_until_ledger: u32,
+    /// Whether every ticket of the event has been covered
+    pub done: bool,
+}
+
+/// Compact on-chain fields of an event, without its descriptive strings
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventSummary {
+    pub id: u64,
+    pub organizer: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub ticket_price: i128,
+    pub max_tickets: u32,
+    pub tickets_sold: u32,
+    pub status: EventStatus,
+    pub metadata_uri: Option<String>,
+    pub content_hash: Option<BytesN<32>>,
+}
+
+impl From<Event> for EventSummary {
+    fn from(event: Event) -> Self {
+        EventSummary {
+            id: event.id,
+            organizer: event.organizer,
+            start_time: event.start_time,
+            end_time: event.end_time,
+            ticket_price: event.ticket_price,
+            max_tickets: event.max_tickets,
+            tickets_sold: event.tickets_sold,
+            status: event.status,
+            metadata_uri: event.metadata_uri,
+            content_hash: event.content_hash,
+        }
+    }
+}
+
+/// Admission session of an event (e.g. one day of a festival)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    pub id: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Admission state of a ticket for one session
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AdmissionState {
+    /// Checked in and currently inside the venue
+    Inside,
+    /// Checked in earlier, then scanned out (re-entry mode only)
+    Outside,
 }
 
 /// Ticket structure
@@ -41,6 +253,148 @@ pub struct Ticket {
     pub purchase_time: u64,
     pub used: bool,
     pub refunded: bool,
+    /// Amount the buyer paid for this ticket
+    pub paid_amount: i128,
+    /// Portion of `paid_amount` collected as platform fee
+    pub platform_fee: i128,
+    /// Portion of `paid_amount` credited to the event escrow
+    pub escrow_amount: i128,
+    /// Session ids this ticket admits to; empty grants every session
+    pub sessions: Vec<u32>,
+}
+
+/// Whether the platform fee is returned along with the escrow share on refund
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RefundPolicy {
+    /// Refund only the escrow share; the platform keeps its fee
+    EscrowOnly,
+    /// Refund the full paid amount, returning the platform fee as well
+    FullAmount,
+}
+
+/// Bundle (e.g. season pass) linking several events under one price and capacity.
+/// Each purchase mints one ticket per event; escrow is split across events by `weights`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bundle {
+    pub id: u64,
+    pub organizer: Address,
+    pub name: String,
+    pub event_ids: Vec<u64>,
+    pub weights: Vec<u32>,
+    pub price: i128,
+    pub max_passes: u32,
+    pub passes_sold: u32,
+}
+
+/// Entry pass scan signed offline by the ticket holder's registered ed25519 key.
+/// `signature` covers [`crate::admission::pass_message`] for the other fields.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedScan {
+    pub ticket_id: u64,
+    pub event_id: u64,
+    /// Scan time; also serves as the replay-protection nonce
+    pub timestamp: u64,
+    pub signature: BytesN<64>,
+}
+
+/// Non-transferable proof-of-attendance badge, minted once per holder and event
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttendanceBadge {
+    pub holder: Address,
+    pub event_id: u64,
+    pub ticket_id: u64,
+    pub checked_in_at: u64,
+}
+
+/// Pending request from a holder to transfer a ticket, awaiting organizer approval
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferRequest {
+    pub ticket_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub requested_at: u64,
+}
+
+/// M-of-N signers whose quorum is required to release or withdraw an event's escrow
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowSigners {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// Escrow movement that signers approve
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowAction {
+    Release,
+    Withdraw,
+}
+
+/// A signer's approval of one escrow movement. It only counts towards a release
+/// or withdrawal with the same action, recipient and amount.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowApproval {
+    pub action: EscrowAction,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// Gift ticket held by the contract until claimed with the preimage of `code_hash`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gift {
+    pub ticket_id: u64,
+    pub purchaser: Address,
+    /// sha256 of the claim secret
+    pub code_hash: BytesN<32>,
+    /// After this time the gift can no longer be claimed and the purchaser may reclaim it
+    pub expires_at: u64,
+}
+
+/// Token-style metadata describing a ticket, for wallets and marketplaces
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketMetadata {
+    pub ticket_id: u64,
+    pub event_id: u64,
+    pub name: String,
+    pub description: String,
+    pub location: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub used: bool,
+    pub refunded: bool,
+}
+
+/// Outcome of a single ticket in a batch check-in or signed scan upload
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CheckInResult {
+    /// Ticket was admitted
+    Ok,
+    /// Ticket does not exist
+    NotFound,
+    /// Ticket belongs to a different event
+    WrongEvent,
+    /// Ticket was already used (or is already inside the current session)
+    AlreadyUsed,
+    /// Ticket was refunded
+    Refunded,
+    /// Ticket was rejected for another admission rule (check-in window, session)
+    Rejected,
+    /// Caller is not a gate agent of the ticket's event
+    Unauthorized,
+    /// Signed scan is not newer than the ticket's last accepted scan, or is dated in the future
+    StaleScan,
+    /// Ticket has no registered entry pass key
+    NoPassKey,
 }
 
 /// Fee collected event for tracking platform fees
@@ -52,3 +406,31 @@ pub struct FeeCollectedEvent {
     pub platform_fee: i128,
     pub organizer_amount: i128,
 }
+
+/// Record class migrated from the legacy storage layout, in migration order
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MigrationPhase {
+    /// Instance config and counters
+    Config,
+    /// Events with their escrow balances
+    Events,
+    /// Tickets, indexed under their events and counted for their owners
+    Tickets,
+    /// Nothing left to migrate
+    Done,
+}
+
+/// Progress of the storage migration after a `migrate_storage` call
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationProgress {
+    /// Phase the next call continues in
+    pub phase: MigrationPhase,
+    /// Id of the next record of `phase` to migrate
+    pub next_id: u64,
+    /// Entries moved by this call
+    pub moved: u32,
+    /// Records left under their legacy keys because they did not decode
+    pub skipped: u32,
+}
@@ -1,7 +1,7 @@
 #![allow(clippy::len_zero)]
 
 use crate::error::LumentixError;
-use soroban_sdk::{Address, String};
+use soroban_sdk::{Address, BytesN, String};
 
 /// Validate that an address is not invalid
 /// In Soroban, addresses are validated by the SDK, but we keep this for consistency
@@ -43,6 +43,19 @@ pub fn validate_string_not_empty(s: &String) -> Result<(), LumentixError> {
     Ok(())
 }
 
+/// Validate optional event metadata: a URI, if given, must not be empty,
+/// and a content hash is only meaningful alongside a URI
+pub fn validate_metadata(
+    uri: &Option<String>,
+    content_hash: &Option<BytesN<32>>,
+) -> Result<(), LumentixError> {
+    match uri {
+        Some(uri) => validate_string_not_empty(uri),
+        None if content_hash.is_some() => Err(LumentixError::EmptyString),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +111,23 @@ mod tests {
             Err(LumentixError::EmptyString)
         );
     }
+
+    #[test]
+    fn test_validate_metadata() {
+        let env = Env::default();
+        let uri = String::from_str(&env, "ipfs://bafy");
+        let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        assert!(validate_metadata(&None, &None).is_ok());
+        assert!(validate_metadata(&Some(uri.clone()), &None).is_ok());
+        assert!(validate_metadata(&Some(uri), &Some(hash.clone())).is_ok());
+        assert_eq!(
+            validate_metadata(&Some(String::from_str(&env, "")), &None),
+            Err(LumentixError::EmptyString)
+        );
+        assert_eq!(
+            validate_metadata(&None, &Some(hash)),
+            Err(LumentixError::EmptyString)
+        );
+    }
 }
@@ -4,6 +4,8 @@
 //! pool (admin-only). There is no separate `withdraw_funds` symbol in this crate; organizer-facing escrow release is
 //! [`release_escrow`](crate::lumentix_contract::LumentixContract::release_escrow).
 
+#![allow(irrefutable_let_patterns)]
+
 use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
 use crate::storage;
@@ -37,6 +39,8 @@ fn publish_event(
         &2000u64,
         &100i128,
         &50u32,
+        &None,
+        &None,
     );
     client.update_event_status(&event_id, &EventStatus::Published, organizer);
     event_id
@@ -117,7 +121,7 @@ fn withdraw_platform_fees_min_balance_one() {
 #[test]
 fn withdraw_platform_fees_does_not_touch_escrow() {
     let env = Env::default();
-    let (admin, contract_id, client) = setup(&env);
+    let (admin, _contract_id, client) = setup(&env);
     let organizer = Address::generate(&env);
     let buyer = Address::generate(&env);
 